@@ -17,4 +17,40 @@ pub enum GovernanceError {
     InvalidProgramDataAccount,
     #[msg("NotUpgradeAuthority")]
     NotUpgradeAuthority,
+    #[msg("InvalidQueuedProposal")]
+    InvalidQueuedProposal,
+    #[msg("ProposalNotReady")]
+    ProposalNotReady,
+    #[msg("ProposalAlreadyReady")]
+    ProposalAlreadyReady,
+    #[msg("GovernanceFrozen")]
+    GovernanceFrozen,
+    #[msg("UnsupportedMessageVersion")]
+    UnsupportedMessageVersion,
+    #[msg("NonceRequired")]
+    NonceRequired,
+    #[msg("InvalidOriginNonce")]
+    InvalidOriginNonce,
+    #[msg("StaleNonce")]
+    StaleNonce,
+    #[msg("NonceGap")]
+    NonceGap,
+    #[msg("InvalidDerivedAccount")]
+    InvalidDerivedAccount,
+    #[msg("InvalidAltAccount")]
+    InvalidAltAccount,
+    #[msg("InvalidTreasury")]
+    InvalidTreasury,
+    #[msg("UntrustedRemoteSender")]
+    UntrustedRemoteSender,
+    #[msg("TooManyRemoteSenders")]
+    TooManyRemoteSenders,
+    #[msg("RemoteSenderNotFound")]
+    RemoteSenderNotFound,
+    #[msg("UnknownRemote")]
+    UnknownRemote,
+    #[msg("GuardianRotationNotCancellable")]
+    GuardianRotationNotCancellable,
+    #[msg("CallerAccountNotQueueable")]
+    CallerAccountNotQueueable,
 }
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::*;
+
+#[event]
+pub struct ProposalQueued {
+    pub guid: [u8; 32],
+    pub src_eid: u32,
+    pub eta: i64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub guid: [u8; 32],
+}
+
+#[event]
+pub struct ProposalExpired {
+    pub guid: [u8; 32],
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub guid: [u8; 32],
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct GovernanceFrozenSet {
+    pub frozen: bool,
+}
+
+#[event]
+pub struct GuardianSet {
+    pub guardian: Option<Pubkey>,
+}
+
+#[event]
+pub struct RemoteSenderAdded {
+    pub remote_eid: u32,
+    pub sender: [u8; 32],
+    pub enabled: bool,
+}
+
+#[event]
+pub struct RemoteSenderRemoved {
+    pub remote_eid: u32,
+    pub sender: [u8; 32],
+}
+
+#[event]
+pub struct RemoteRemoved {
+    pub remote_eid: u32,
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{error::GovernanceError, events::RemoteSenderAdded, *};
+
+/// Adds a trusted sender to an existing remote, or updates its `enabled` flag if already present.
+/// Used to rotate the governance sender of a source eid without a window where messages fail.
+#[derive(Accounts)]
+#[instruction(params: AddRemoteSenderParams)]
+pub struct AddRemoteSender<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REMOTE_SEED, &governance.key().to_bytes(), &params.remote_eid.to_be_bytes()],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()],
+        bump = governance.bump,
+        has_one = admin @GovernanceError::Unauthorized
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+impl AddRemoteSender<'_> {
+    pub fn apply(ctx: &mut Context<AddRemoteSender>, params: &AddRemoteSenderParams) -> Result<()> {
+        let senders = &mut ctx.accounts.remote.senders;
+        match senders.iter_mut().find(|s| s.address == params.sender) {
+            Some(sender) => sender.enabled = params.enabled,
+            None => {
+                require!(senders.len() < MAX_REMOTE_SENDERS, GovernanceError::TooManyRemoteSenders);
+                senders.push(RemoteSender {
+                    address: params.sender,
+                    enabled: params.enabled,
+                });
+            }
+        }
+        emit!(RemoteSenderAdded {
+            remote_eid: params.remote_eid,
+            sender: params.sender,
+            enabled: params.enabled,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AddRemoteSenderParams {
+    pub remote_eid: u32,
+    pub sender: [u8; 32],
+    pub enabled: bool,
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    error::GovernanceError, events::ProposalCancelled,
+    instructions::set_guardian::is_guardian_rotation, msg_codec::GovernancePayload, *,
+};
+
+/// Guardian veto of a queued proposal. The proposal is closed before its ETA and can never be
+/// executed. Guardian rotations can not be vetoed, so that the guardian can always be replaced.
+#[derive(Accounts)]
+#[instruction(params: CancelProposalParams)]
+pub struct CancelProposal<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()],
+        bump = governance.bump,
+        constraint = governance.guardian == Some(guardian.key()) @GovernanceError::Unauthorized
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [QUEUED_PROPOSAL_SEED, &governance.key().to_bytes(), &params.guid],
+        bump = queued_proposal.bump,
+        close = rent_recipient
+    )]
+    pub queued_proposal: Account<'info, QueuedProposal>,
+
+    /// CHECK: the payer that funded the queued proposal, receives its rent back
+    #[account(mut, address = queued_proposal.payer)]
+    pub rent_recipient: AccountInfo<'info>,
+}
+
+impl CancelProposal<'_> {
+    pub fn apply(ctx: &mut Context<CancelProposal>, params: &CancelProposalParams) -> Result<()> {
+        require!(
+            !ctx.accounts.queued_proposal.is_ready(Clock::get()?.unix_timestamp),
+            GovernanceError::ProposalAlreadyReady
+        );
+        if let Ok(payload) = GovernancePayload::from_bytes(&ctx.accounts.queued_proposal.message) {
+            require!(!is_guardian_rotation(&payload), GovernanceError::GuardianRotationNotCancellable);
+        }
+        emit!(ProposalCancelled {
+            guid: params.guid,
+            guardian: ctx.accounts.guardian.key(),
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CancelProposalParams {
+    pub guid: [u8; 32],
+}
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    error::GovernanceError,
+    events::{ProposalExecuted, ProposalExpired},
+    instructions::{
+        lz_receive::{execute_governance_payload, record_origin_nonce, transfer_value},
+        set_guardian::is_guardian_rotation,
+    },
+    msg_codec::{GovernanceMessage, GovernancePayload},
+    *,
+};
+use oapp::LZ_RECEIVE_TYPES_SEED;
+
+/// Permissionless execution of a queued governance message once its ETA has passed.
+/// The remaining accounts are the accounts of the governed instruction, `execute_queued_types`
+/// returns the complete account plan. Queued messages can not reference the payer or the
+/// execution context, as they would resolve to whoever calls this instruction.
+#[derive(Accounts)]
+#[instruction(params: ExecuteQueuedParams)]
+pub struct ExecuteQueued<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+
+    /// CHECK: the remote of the proposal source eid, loaded in apply so that a removed remote fails with `UnknownRemote`
+    #[account(
+        seeds = [REMOTE_SEED, &governance.key().to_bytes(), &queued_proposal.src_eid.to_be_bytes()],
+        bump
+    )]
+    pub remote: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [QUEUED_PROPOSAL_SEED, &governance.key().to_bytes(), &params.guid],
+        bump = queued_proposal.bump
+    )]
+    pub queued_proposal: Account<'info, QueuedProposal>,
+
+    /// CHECK: the payer that funded the queued proposal, receives its rent back
+    #[account(mut, address = queued_proposal.payer)]
+    pub rent_recipient: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [CPI_AUTHORITY_SEED, &governance.key().to_bytes(), &queued_proposal.src_eid.to_be_bytes(), &GovernanceMessage::decode_origin_caller(&queued_proposal.message).unwrap()],
+        bump
+    )]
+    pub cpi_authority: AccountInfo<'info>,
+
+    #[account(executable)]
+    pub program: UncheckedAccount<'info>,
+
+    /// Only supplied when the message carries a nonce, tracks the last executed nonce of the origin caller
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + OriginNonce::INIT_SPACE,
+        seeds = [ORIGIN_NONCE_SEED, &governance.key().to_bytes(), &queued_proposal.src_eid.to_be_bytes(), &GovernanceMessage::decode_origin_caller(&queued_proposal.message).unwrap()],
+        bump
+    )]
+    pub origin_nonce: Option<Account<'info, OriginNonce>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MessageOrigin::INIT_SPACE,
+        seeds = [MESSAGE_ORIGIN_SEED, &governance.key().to_bytes()],
+        bump
+    )]
+    pub message_origin: Account<'info, MessageOrigin>,
+
+    /// Holds the ALTs referenced by compact account lists
+    #[account(
+        seeds = [LZ_RECEIVE_TYPES_SEED, &governance.key().to_bytes()],
+        bump = lz_receive_types_accounts.bump
+    )]
+    pub lz_receive_types_accounts: Account<'info, GovernanceLzReceiveTypesAccounts>,
+
+    /// Only supplied when the message value is paid by the treasury
+    #[account(mut, seeds = [TREASURY_SEED, &governance.key().to_bytes()], bump)]
+    pub treasury: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExecuteQueued<'info> {
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, Self>,
+        params: &ExecuteQueuedParams,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.queued_proposal.is_ready(Clock::get()?.unix_timestamp),
+            GovernanceError::ProposalNotReady
+        );
+
+        let QueuedProposal { src_eid, sender, nonce, guid, .. } = *ctx.accounts.queued_proposal;
+        // a sender removed or disabled while the proposal is queued can no longer have it executed
+        let remote = Remote::load(&ctx.accounts.remote)?;
+        require!(remote.is_trusted(&sender), GovernanceError::UntrustedRemoteSender);
+        let message = std::mem::take(&mut ctx.accounts.queued_proposal.message);
+
+        let mut governance_payload: GovernancePayload = GovernancePayload::from_bytes(&message)?;
+        require!(
+            governance_payload.program_id == ctx.accounts.program.key(),
+            GovernanceError::GovernedProgramIdMismatch
+        );
+        require!(
+            !ctx.accounts.governance.frozen || is_guardian_rotation(&governance_payload),
+            GovernanceError::GovernanceFrozen
+        );
+
+        // Close the proposal before the CPI so it can never be executed twice
+        ctx.accounts.queued_proposal.close(ctx.accounts.rent_recipient.to_account_info())?;
+
+        // Expired messages still consume their nonce so they don't block the origin caller
+        record_origin_nonce(
+            &mut ctx.accounts.origin_nonce,
+            ctx.bumps.origin_nonce,
+            remote.strict_ordering,
+            governance_payload.nonce,
+        )?;
+        if governance_payload.is_expired(Clock::get()?.unix_timestamp) {
+            emit!(ProposalExpired { guid: params.guid });
+            return Ok(());
+        }
+
+        governance_payload.resolve_alt_accounts(&ctx.accounts.lz_receive_types_accounts.alts, ctx.remaining_accounts)?;
+        // entries of referenced ALTs are only known once resolved
+        require!(!governance_payload.uses_caller_accounts(), GovernanceError::CallerAccountNotQueueable);
+
+        if let Some(value) = &governance_payload.value {
+            transfer_value(
+                value,
+                &ctx.accounts.governance.key(),
+                &ctx.accounts.payer,
+                &ctx.accounts.treasury,
+                ctx.bumps.treasury,
+                &ctx.accounts.cpi_authority,
+                &ctx.accounts.system_program,
+            )?;
+        }
+
+        let origin_caller = governance_payload.origin_caller;
+        execute_governance_payload(
+            governance_payload,
+            &ctx.accounts.governance.executor_id,
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.cpi_authority,
+            &[
+                CPI_AUTHORITY_SEED,
+                &ctx.accounts.governance.key().to_bytes(),
+                &src_eid.to_be_bytes(),
+                &origin_caller,
+                &[ctx.bumps.cpi_authority],
+            ],
+            &mut ctx.accounts.message_origin,
+            MessageOrigin {
+                src_eid,
+                sender,
+                origin_caller,
+                guid,
+                nonce,
+                bump: ctx.bumps.message_origin,
+            },
+            ctx.remaining_accounts,
+        )?;
+
+        emit!(ProposalExecuted { guid: params.guid });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteQueuedParams {
+    pub guid: [u8; 32],
+}
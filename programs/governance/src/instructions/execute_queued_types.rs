@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    instructions::lz_receive_types_v2::governed_accounts,
+    msg_codec::{GovernancePayload, Value, ValueSource},
+    *,
+};
+use anchor_lang::InstructionData;
+use oapp::common::{compact_accounts_with_alts, AccountMetaRef, AddressLocator, EXECUTION_CONTEXT_VERSION_1};
+use oapp::lz_receive_types_v2::{Instruction, LzReceiveTypesV2Result};
+use oapp::LZ_RECEIVE_TYPES_SEED;
+
+/// Returns the execution plan of `execute_queued` for a queued proposal, the counterpart of
+/// `lz_receive_types_v2` which only covers storing the message when the execution queue is enabled.
+/// The remaining accounts are the ALTs of `GovernanceLzReceiveTypesAccounts`, in order.
+#[derive(Accounts)]
+#[instruction(params: ExecuteQueuedParams)]
+pub struct ExecuteQueuedTypes<'info> {
+    #[account(seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        seeds = [QUEUED_PROPOSAL_SEED, &governance.key().to_bytes(), &params.guid],
+        bump = queued_proposal.bump
+    )]
+    pub queued_proposal: Account<'info, QueuedProposal>,
+}
+
+impl ExecuteQueuedTypes<'_> {
+    pub fn apply(
+        ctx: &Context<ExecuteQueuedTypes>,
+        params: &ExecuteQueuedParams,
+    ) -> Result<LzReceiveTypesV2Result> {
+        let governance = ctx.accounts.governance.key();
+        let queued_proposal = &ctx.accounts.queued_proposal;
+        let src_eid = queued_proposal.src_eid.to_be_bytes();
+        let (remote, _) = Pubkey::find_program_address(&[REMOTE_SEED, &governance.to_bytes(), &src_eid], ctx.program_id);
+
+        let mut governance_payload: GovernancePayload = GovernancePayload::from_bytes(&queued_proposal.message)?;
+
+        let (cpi_authority, _) = Pubkey::find_program_address(&[CPI_AUTHORITY_SEED, &governance.to_bytes(), &src_eid, &governance_payload.origin_caller], ctx.program_id);
+        let tracks_nonce = governance_payload.nonce.is_some();
+        let origin_nonce = if tracks_nonce {
+            let (origin_nonce, _) = Pubkey::find_program_address(&[ORIGIN_NONCE_SEED, &governance.to_bytes(), &src_eid, &governance_payload.origin_caller], ctx.program_id);
+            origin_nonce
+        } else {
+            // optional account placeholder
+            *ctx.program_id
+        };
+        let (message_origin, _) = Pubkey::find_program_address(&[MESSAGE_ORIGIN_SEED, &governance.to_bytes()], ctx.program_id);
+        let from_treasury = matches!(governance_payload.value, Some(Value { source: ValueSource::Treasury, .. }));
+        let treasury = if from_treasury {
+            let (treasury, _) = Pubkey::find_program_address(&[TREASURY_SEED, &governance.to_bytes()], ctx.program_id);
+            treasury
+        } else {
+            // optional account placeholder
+            *ctx.program_id
+        };
+        let (lz_receive_types_accounts, _) = Pubkey::find_program_address(&[LZ_RECEIVE_TYPES_SEED, &governance.to_bytes()], ctx.program_id);
+
+        let mut accounts = vec![
+            // payer
+            AccountMetaRef {
+                pubkey: AddressLocator::Payer,
+                is_writable: true,
+            },
+            // governance
+            AccountMetaRef {
+                pubkey: governance.into(),
+                is_writable: false,
+            },
+            // remote
+            AccountMetaRef {
+                pubkey: remote.into(),
+                is_writable: false,
+            },
+            // queued proposal
+            AccountMetaRef {
+                pubkey: queued_proposal.key().into(),
+                is_writable: true,
+            },
+            // rent recipient
+            AccountMetaRef {
+                pubkey: queued_proposal.payer.into(),
+                is_writable: true,
+            },
+            // cpi authority, receives the message value
+            AccountMetaRef {
+                pubkey: cpi_authority.into(),
+                is_writable: true,
+            },
+            // program
+            AccountMetaRef {
+                pubkey: governance_payload.program_id.into(),
+                is_writable: false,
+            },
+            // origin nonce
+            AccountMetaRef {
+                pubkey: origin_nonce.into(),
+                is_writable: tracks_nonce,
+            },
+            // message origin
+            AccountMetaRef {
+                pubkey: message_origin.into(),
+                is_writable: true,
+            },
+            // lz receive types accounts
+            AccountMetaRef {
+                pubkey: lz_receive_types_accounts.into(),
+                is_writable: false,
+            },
+            // treasury
+            AccountMetaRef {
+                pubkey: treasury.into(),
+                is_writable: from_treasury,
+            },
+            // system program
+            AccountMetaRef {
+                pubkey: solana_program::system_program::ID.into(),
+                is_writable: false,
+            },
+        ];
+        // the governed instruction accounts, followed by the referenced ALTs
+        accounts.extend(governed_accounts(
+            &mut governance_payload,
+            &cpi_authority,
+            &message_origin,
+            ctx.remaining_accounts,
+        )?);
+
+        Ok(LzReceiveTypesV2Result {
+            context_version: EXECUTION_CONTEXT_VERSION_1,
+            alts: ctx.remaining_accounts.iter().map(|alt| alt.key()).collect(),
+            instructions: vec![Instruction::Standard {
+                program_id: *ctx.program_id,
+                accounts: compact_accounts_with_alts(ctx.remaining_accounts, accounts)?,
+                data: crate::instruction::ExecuteQueued { params: params.clone() }.data(),
+            }],
+        })
+    }
+}
@@ -48,6 +48,11 @@ impl InitGovernance<'_> {
         ctx.accounts.governance.id = params.id;
         ctx.accounts.governance.admin = params.admin;
         ctx.accounts.governance.bump = ctx.bumps.governance;
+        ctx.accounts.governance.min_delay = params.min_delay;
+        ctx.accounts.governance.guardian = params.guardian;
+        ctx.accounts.governance.frozen = false;
+        ctx.accounts.governance.executor_id = params.executor_id;
+        ctx.accounts.governance.version = GOVERNANCE_VERSION;
         ctx.accounts.lz_receive_types_v2_accounts.alts = params.lz_receive_alts.clone();
         ctx.accounts.lz_receive_types_v2_accounts.bump = ctx.bumps.lz_receive_types_v2_accounts;
 
@@ -77,4 +82,7 @@ pub struct InitGovernanceParams {
     pub id: u64,
     pub admin: Pubkey,
     pub lz_receive_alts: Vec<Pubkey>,
+    pub min_delay: u64,
+    pub guardian: Option<Pubkey>,
+    pub executor_id: Pubkey,
 }
@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    CONTEXT_PLACEHOLDER, CPI_AUTHORITY_SEED, EXECUTOR_ID, GOVERNANCE_SEED, PAYER_PLACEHOLDER, REMOTE_SEED, CPI_AUTHORITY_PLACEHOLDER,
+    derived_placeholder_index, CONTEXT_PLACEHOLDER, CPI_AUTHORITY_SEED, GOVERNANCE_SEED, MESSAGE_ORIGIN_SEED, ORIGIN_PLACEHOLDER, TREASURY_SEED, PAYER_PLACEHOLDER, ORIGIN_NONCE_SEED, QUEUED_PROPOSAL_SEED, REMOTE_SEED, CPI_AUTHORITY_PLACEHOLDER,
     error::GovernanceError,
-    msg_codec::GovernanceMessage,
-    state::{Governance, Remote},
+    events::{ProposalExpired, ProposalQueued},
+    instructions::set_guardian::is_guardian_rotation,
+    msg_codec::{GovernanceMessage, GovernancePayload, Value, ValueSource},
+    state::{Governance, GovernanceLzReceiveTypesAccounts, MessageOrigin, OriginNonce, QueuedProposal, Remote},
 };
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
@@ -12,7 +14,7 @@ use oapp::{
     endpoint::{
         cpi::accounts::Clear, instructions::ClearParams, ConstructCPIContext, ID as ENDPOINT_ID,
     },
-    LzReceiveParams,
+    LzReceiveParams, LZ_RECEIVE_TYPES_SEED,
 };
 use solana_program::instruction::Instruction;
 
@@ -25,14 +27,15 @@ pub struct LzReceive<'info> {
     #[account(seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()], bump = governance.bump)]
     pub governance: Account<'info, Governance>,
 
+    /// CHECK: the remote of the source eid, loaded in apply so that a removed remote fails with `UnknownRemote`
     #[account(
         seeds = [REMOTE_SEED, &governance.key().to_bytes(), &params.src_eid.to_be_bytes()],
-        bump = remote.bump,
-        constraint = params.sender == remote.address
+        bump
     )]
-    pub remote: Account<'info, Remote>,
+    pub remote: UncheckedAccount<'info>,
 
     #[account(
+        mut,
         seeds = [CPI_AUTHORITY_SEED, &governance.key().to_bytes(), &params.src_eid.to_be_bytes(), &GovernanceMessage::decode_origin_caller(&params.message).unwrap()],
         bump
     )]
@@ -40,6 +43,48 @@ pub struct LzReceive<'info> {
 
     #[account(executable)]
     pub program: UncheckedAccount<'info>,
+
+    /// Only supplied when `governance.min_delay` is set, the message is stored instead of executed
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + QueuedProposal::init_space(params.message.len()),
+        seeds = [QUEUED_PROPOSAL_SEED, &governance.key().to_bytes(), &params.guid],
+        bump
+    )]
+    pub queued_proposal: Option<Account<'info, QueuedProposal>>,
+
+    /// Only supplied when the message carries a nonce, tracks the last executed nonce of the origin caller
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + OriginNonce::INIT_SPACE,
+        seeds = [ORIGIN_NONCE_SEED, &governance.key().to_bytes(), &params.src_eid.to_be_bytes(), &GovernanceMessage::decode_origin_caller(&params.message).unwrap()],
+        bump
+    )]
+    pub origin_nonce: Option<Account<'info, OriginNonce>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MessageOrigin::INIT_SPACE,
+        seeds = [MESSAGE_ORIGIN_SEED, &governance.key().to_bytes()],
+        bump
+    )]
+    pub message_origin: Account<'info, MessageOrigin>,
+
+    /// Holds the ALTs referenced by compact account lists
+    #[account(
+        seeds = [LZ_RECEIVE_TYPES_SEED, &governance.key().to_bytes()],
+        bump = lz_receive_types_accounts.bump
+    )]
+    pub lz_receive_types_accounts: Account<'info, GovernanceLzReceiveTypesAccounts>,
+
+    /// Only supplied when the message value is paid by the treasury
+    #[account(mut, seeds = [TREASURY_SEED, &governance.key().to_bytes()], bump)]
+    pub treasury: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> LzReceive<'info> {
@@ -47,6 +92,9 @@ impl<'info> LzReceive<'info> {
         ctx: &mut Context<'_, '_, '_, 'info, Self>,
         params: &LzReceiveParams,
     ) -> Result<()> {
+        let remote = Remote::load(&ctx.accounts.remote)?;
+        require!(remote.is_trusted(&params.sender), GovernanceError::UntrustedRemoteSender);
+
         let governance_seed: &[&[u8]] = &[
             GOVERNANCE_SEED,
             &ctx.accounts.governance.id.to_be_bytes(),
@@ -71,53 +119,224 @@ impl<'info> LzReceive<'info> {
         )?;
 
         // Decode governance message from LayerZero message
-        let governance_message: GovernanceMessage = GovernanceMessage::from_bytes(&params.message)?;
+        let mut governance_payload: GovernancePayload = GovernancePayload::from_bytes(&params.message)?;
 
         // Assert supplied program id matches the governed program id from the message
         require!(
-            governance_message.program_id == ctx.accounts.program.key(),
+            governance_payload.program_id == ctx.accounts.program.key(),
             GovernanceError::GovernedProgramIdMismatch
         );
 
-        let origin_caller = governance_message.origin_caller;
-        let mut instruction: Instruction = governance_message.into();
+        if ctx.accounts.governance.min_delay > 0 {
+            // Queue the message, it is executed by execute_queued once the delay has passed
+            let queued_proposal = ctx
+                .accounts
+                .queued_proposal
+                .as_mut()
+                .ok_or(GovernanceError::InvalidQueuedProposal)?;
+            // the payer and the execution context would resolve to the caller of execute_queued
+            require!(!governance_payload.uses_caller_accounts(), GovernanceError::CallerAccountNotQueueable);
+            let eta = QueuedProposal::eta(Clock::get()?.unix_timestamp, ctx.accounts.governance.min_delay);
 
-        let (execution_context_addr, _) = Pubkey::find_program_address(
+            queued_proposal.src_eid = params.src_eid;
+            queued_proposal.sender = params.sender;
+            queued_proposal.nonce = params.nonce;
+            queued_proposal.guid = params.guid;
+            queued_proposal.eta = eta;
+            queued_proposal.payer = ctx.accounts.payer.key();
+            queued_proposal.bump = ctx.bumps.queued_proposal;
+            queued_proposal.message = params.message.clone();
+
+            emit!(ProposalQueued {
+                guid: params.guid,
+                src_eid: params.src_eid,
+                eta,
+            });
+            return Ok(());
+        }
+        require!(ctx.accounts.queued_proposal.is_none(), GovernanceError::InvalidQueuedProposal);
+        require!(
+            !ctx.accounts.governance.frozen || is_guardian_rotation(&governance_payload),
+            GovernanceError::GovernanceFrozen
+        );
+
+        // Expired messages still consume their nonce so they don't block the origin caller
+        record_origin_nonce(
+            &mut ctx.accounts.origin_nonce,
+            ctx.bumps.origin_nonce,
+            remote.strict_ordering,
+            governance_payload.nonce,
+        )?;
+        if governance_payload.is_expired(Clock::get()?.unix_timestamp) {
+            emit!(ProposalExpired { guid: params.guid });
+            return Ok(());
+        }
+
+        let governed_accounts = &ctx.remaining_accounts[Clear::MIN_ACCOUNTS_LEN..];
+        governance_payload.resolve_alt_accounts(&ctx.accounts.lz_receive_types_accounts.alts, governed_accounts)?;
+
+        if let Some(value) = &governance_payload.value {
+            transfer_value(
+                value,
+                &ctx.accounts.governance.key(),
+                &ctx.accounts.payer,
+                &ctx.accounts.treasury,
+                ctx.bumps.treasury,
+                &ctx.accounts.cpi_authority,
+                &ctx.accounts.system_program,
+            )?;
+        }
+
+        let origin_caller = governance_payload.origin_caller;
+        execute_governance_payload(
+            governance_payload,
+            &ctx.accounts.governance.executor_id,
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.cpi_authority,
             &[
-                EXECUTION_CONTEXT_SEED,
-                &ctx.accounts.payer.key.to_bytes(),
-                &[EXECUTION_CONTEXT_VERSION_1],
+                CPI_AUTHORITY_SEED,
+                &ctx.accounts.governance.key().to_bytes(),
+                &params.src_eid.to_be_bytes(),
+                &origin_caller,
+                &[ctx.bumps.cpi_authority],
             ],
-            &EXECUTOR_ID,
-        );
+            &mut ctx.accounts.message_origin,
+            MessageOrigin {
+                src_eid: params.src_eid,
+                sender: params.sender,
+                origin_caller,
+                guid: params.guid,
+                nonce: params.nonce,
+                bump: ctx.bumps.message_origin,
+            },
+            governed_accounts,
+        )
+    }
+}
+
+/// Replaces the placeholder accounts of each instruction of a governance message and invokes the
+/// governed program in order, signed by the CPI authority. The message origin is only set for the
+/// duration of the CPIs. Shared by `lz_receive` and `execute_queued`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn execute_governance_payload<'info>(
+    governance_payload: GovernancePayload,
+    executor_id: &Pubkey,
+    payer: &Pubkey,
+    cpi_authority: &AccountInfo<'info>,
+    cpi_authority_seeds: &[&[u8]],
+    message_origin: &mut Account<'info, MessageOrigin>,
+    origin: MessageOrigin,
+    account_infos: &[AccountInfo<'info>],
+) -> Result<()> {
+    // Persist the origin before the CPI, Anchor only writes accounts back when the instruction exits
+    message_origin.set_inner(origin);
+    message_origin.exit(&crate::ID)?;
+
+    let (execution_context_addr, _) = Pubkey::find_program_address(
+        &[
+            EXECUTION_CONTEXT_SEED,
+            &payer.to_bytes(),
+            &[EXECUTION_CONTEXT_VERSION_1],
+        ],
+        executor_id,
+    );
+    let derived_accounts = governance_payload.resolve_derived_accounts(&cpi_authority.key(), &message_origin.key())?;
+
+    for governance_message in governance_payload.instructions {
+        let mut instruction: Instruction = governance_message.into();
 
         // Replace placeholder accounts
-        instruction.accounts.iter_mut().for_each(|acc| {
+        for acc in instruction.accounts.iter_mut() {
             if acc.pubkey == CPI_AUTHORITY_PLACEHOLDER {
-                acc.pubkey = ctx.accounts.cpi_authority.key();
+                acc.pubkey = cpi_authority.key();
             } else if acc.pubkey == PAYER_PLACEHOLDER {
-                acc.pubkey = ctx.accounts.payer.key();
+                acc.pubkey = *payer;
             } else if acc.pubkey == CONTEXT_PLACEHOLDER {
                 acc.pubkey = execution_context_addr;
+            } else if acc.pubkey == ORIGIN_PLACEHOLDER {
+                acc.pubkey = message_origin.key();
+            } else if let Some(index) = derived_placeholder_index(&acc.pubkey) {
+                acc.pubkey = *derived_accounts.get(index).ok_or(GovernanceError::InvalidDerivedAccount)?;
             }
-        });
+        }
 
-        solana_program::program::invoke_signed(&instruction, &ctx.remaining_accounts[Clear::MIN_ACCOUNTS_LEN..], &[
-            &[   
-                CPI_AUTHORITY_SEED,
-                &ctx.accounts.governance.key().to_bytes(),
-                &params.src_eid.to_be_bytes(),
-                &origin_caller,
-                &[ctx.bumps.cpi_authority],
-            ]
-        ])?;
+        solana_program::program::invoke_signed(&instruction, account_infos, &[cpi_authority_seeds])?;
+    }
 
-        require!(
-            ctx.accounts.cpi_authority.owner.key() == system_program::ID,
-            GovernanceError::CpiAuthorityOwnerNotSystemProgram
-        );
-        require!(ctx.accounts.cpi_authority.data_is_empty(), GovernanceError::CpiAuthorityDataNotEmpty);
+    message_origin.clear();
+    message_origin.exit(&crate::ID)?;
+
+    require!(
+        cpi_authority.owner.key() == system_program::ID,
+        GovernanceError::CpiAuthorityOwnerNotSystemProgram
+    );
+    require!(cpi_authority.data_is_empty(), GovernanceError::CpiAuthorityDataNotEmpty);
+
+    Ok(())
+}
+
+/// Checks the nonce of a governance message against the last executed nonce of its origin caller
+/// and records it. Without `strict_ordering` the nonce is recorded as is, so that strict ordering
+/// continues from the last executed message once re-enabled.
+pub(crate) fn record_origin_nonce(
+    origin_nonce: &mut Option<Account<OriginNonce>>,
+    bump: u8,
+    strict_ordering: bool,
+    nonce: Option<u64>,
+) -> Result<()> {
+    let nonce = match nonce {
+        Some(nonce) => nonce,
+        None => {
+            require!(!strict_ordering, GovernanceError::NonceRequired);
+            return Ok(());
+        }
+    };
+    let origin_nonce = origin_nonce.as_mut().ok_or(GovernanceError::InvalidOriginNonce)?;
+
+    if strict_ordering {
+        require!(nonce > origin_nonce.nonce, GovernanceError::StaleNonce);
+        require!(nonce - 1 == origin_nonce.nonce, GovernanceError::NonceGap);
+    }
+    origin_nonce.nonce = nonce;
+    origin_nonce.bump = bump;
+    Ok(())
+}
 
-        Ok(())
+/// Transfers the value of a governance message to the CPI authority, from the payer or from the
+/// treasury PDA.
+pub(crate) fn transfer_value<'info>(
+    value: &Value,
+    governance: &Pubkey,
+    payer: &Signer<'info>,
+    treasury: &Option<SystemAccount<'info>>,
+    treasury_bump: u8,
+    cpi_authority: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    match value.source {
+        ValueSource::Payer => system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: cpi_authority.clone(),
+                },
+            ),
+            value.lamports,
+        ),
+        ValueSource::Treasury => {
+            let treasury = treasury.as_ref().ok_or(GovernanceError::InvalidTreasury)?;
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: treasury.to_account_info(),
+                        to: cpi_authority.clone(),
+                    },
+                    &[&[TREASURY_SEED, &governance.to_bytes(), &[treasury_bump]]],
+                ),
+                value.lamports,
+            )
+        }
     }
 }
@@ -1,13 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0
-use crate::*;
-use crate::msg_codec::GovernanceMessage;
+use crate::{error::GovernanceError, *};
+use crate::msg_codec::{GovernancePayload, Value, ValueSource};
 use oapp::common::{
     compact_accounts_with_alts, AccountMetaRef, AddressLocator, EXECUTION_CONTEXT_VERSION_1,
 };
 use oapp::lz_receive_types_v2::{
     get_accounts_for_clear, Instruction, LzReceiveTypesV2Result,
 };
-use oapp::{endpoint::ID as ENDPOINT_ID, LzReceiveParams};
+use oapp::{endpoint::ID as ENDPOINT_ID, LzReceiveParams, LZ_RECEIVE_TYPES_SEED};
 
 /// LzReceiveTypesV2 instruction implements the V2 framework for resolving account dependencies.
 ///
@@ -40,11 +40,44 @@ impl LzReceiveTypesV2<'_> {
         let governance = ctx.accounts.governance.key();
         let (remote, _) = Pubkey::find_program_address(&[REMOTE_SEED, &governance.to_bytes(), &params.src_eid.to_be_bytes()], ctx.program_id);
 
-        let governance_message: GovernanceMessage = GovernanceMessage::from_bytes(&params.message)?;
+        let mut governance_payload: GovernancePayload = GovernancePayload::from_bytes(&params.message)?;
 
-        let (cpi_authority, _) = Pubkey::find_program_address(&[CPI_AUTHORITY_SEED, &governance.to_bytes(), &params.src_eid.to_be_bytes(), &governance_message.origin_caller], ctx.program_id);
+        let (cpi_authority, _) = Pubkey::find_program_address(&[CPI_AUTHORITY_SEED, &governance.to_bytes(), &params.src_eid.to_be_bytes(), &governance_payload.origin_caller], ctx.program_id);
 
-        // accounts indexes 0 to 4 inclusive (first 5 accounts)
+        // Messages are only stored when the execution queue is enabled, the governed instruction runs in
+        // execute_queued whose plan is returned by execute_queued_types
+        let queued = ctx.accounts.governance.min_delay > 0;
+        let queued_proposal = if queued {
+            let (queued_proposal, _) = Pubkey::find_program_address(&[QUEUED_PROPOSAL_SEED, &governance.to_bytes(), &params.guid], ctx.program_id);
+            queued_proposal
+        } else {
+            // optional account placeholder
+            *ctx.program_id
+        };
+        // The origin nonce is only tracked when the message is executed and carries a nonce
+        let tracks_nonce = !queued && governance_payload.nonce.is_some();
+        let origin_nonce = if tracks_nonce {
+            let (origin_nonce, _) = Pubkey::find_program_address(&[ORIGIN_NONCE_SEED, &governance.to_bytes(), &params.src_eid.to_be_bytes(), &governance_payload.origin_caller], ctx.program_id);
+            origin_nonce
+        } else {
+            // optional account placeholder
+            *ctx.program_id
+        };
+
+        let (message_origin, _) = Pubkey::find_program_address(&[MESSAGE_ORIGIN_SEED, &governance.to_bytes()], ctx.program_id);
+        // The treasury is only needed when it pays the value of an executed message
+        let from_treasury = !queued
+            && matches!(governance_payload.value, Some(Value { source: ValueSource::Treasury, .. }));
+        let treasury = if from_treasury {
+            let (treasury, _) = Pubkey::find_program_address(&[TREASURY_SEED, &governance.to_bytes()], ctx.program_id);
+            treasury
+        } else {
+            // optional account placeholder
+            *ctx.program_id
+        };
+        let (lz_receive_types_accounts, _) = Pubkey::find_program_address(&[LZ_RECEIVE_TYPES_SEED, &governance.to_bytes()], ctx.program_id);
+
+        // accounts indexes 0 to 10 inclusive (first 11 accounts)
         let mut accounts = vec![
             // payer
             AccountMetaRef {
@@ -61,19 +94,49 @@ impl LzReceiveTypesV2<'_> {
                 pubkey: remote.into(),
                 is_writable: false,
             },
-            // cpi authority
+            // cpi authority, receives the message value
             AccountMetaRef {
                 pubkey: cpi_authority.into(),
-                is_writable: false,
+                is_writable: true,
             },
             // program
             AccountMetaRef {
-                pubkey: governance_message.program_id.into(),
+                pubkey: governance_payload.program_id.into(),
+                is_writable: false,
+            },
+            // queued proposal
+            AccountMetaRef {
+                pubkey: queued_proposal.into(),
+                is_writable: queued,
+            },
+            // origin nonce
+            AccountMetaRef {
+                pubkey: origin_nonce.into(),
+                is_writable: tracks_nonce,
+            },
+            // message origin
+            AccountMetaRef {
+                pubkey: message_origin.into(),
+                is_writable: true,
+            },
+            // lz receive types accounts
+            AccountMetaRef {
+                pubkey: lz_receive_types_accounts.into(),
+                is_writable: false,
+            },
+            // treasury
+            AccountMetaRef {
+                pubkey: treasury.into(),
+                is_writable: from_treasury,
+            },
+            // system program
+            AccountMetaRef {
+                pubkey: solana_program::system_program::ID.into(),
                 is_writable: false,
             },
         ];
 
-        // accounts indexes 5 to 12 inclusive (8 accounts, last one #13)
+        // accounts indexes 11 to 18 inclusive (8 accounts, last one #19)
         // Add accounts required for LayerZero's Endpoint clear operation
         // These accounts handle the core message verification and processing
         let accounts_for_clear: Vec<AccountMetaRef> = get_accounts_for_clear(
@@ -85,25 +148,17 @@ impl LzReceiveTypesV2<'_> {
         );
         accounts.extend(accounts_for_clear);
 
-        // accounts indexes starting from 13
-        // Governance message instruction accounts
-        accounts.extend(
-            governance_message
-                .accounts
-                .iter()
-                .map(|acc| AccountMetaRef {
-                    pubkey: if acc.pubkey == CPI_AUTHORITY_PLACEHOLDER {
-                        cpi_authority.into()
-                    } else if acc.pubkey == PAYER_PLACEHOLDER {
-                        AddressLocator::Payer
-                    } else if acc.pubkey == CONTEXT_PLACEHOLDER {
-                        AddressLocator::Context
-                    } else {
-                        acc.pubkey.into()
-                    },
-                    is_writable: acc.is_writable,
-                }),
-        );
+        // accounts indexes starting from 19
+        // Union of the governance message instruction accounts, not needed when the message is only queued
+        if !queued {
+            // the remaining accounts are the ALTs of GovernanceLzReceiveTypesAccounts, in order
+            accounts.extend(governed_accounts(
+                &mut governance_payload,
+                &cpi_authority,
+                &message_origin,
+                ctx.remaining_accounts,
+            )?);
+        }
 
         // Return the complete execution plan with ALTs and instructions
         Ok(LzReceiveTypesV2Result {
@@ -118,3 +173,44 @@ impl LzReceiveTypesV2<'_> {
         })
     }
 }
+
+/// Resolves the placeholders of the governance message accounts, followed by the ALTs referenced by
+/// compact account lists. `alts` are the ALTs of `GovernanceLzReceiveTypesAccounts`, in order.
+/// Shared by `lz_receive_types_v2` and `execute_queued_types`.
+pub(crate) fn governed_accounts(
+    governance_payload: &mut GovernancePayload,
+    cpi_authority: &Pubkey,
+    message_origin: &Pubkey,
+    alts: &[AccountInfo],
+) -> Result<Vec<AccountMetaRef>> {
+    let alt_keys: Vec<Pubkey> = alts.iter().map(|alt| alt.key()).collect();
+    let referenced_alts = governance_payload.resolve_alt_accounts(&alt_keys, alts)?;
+    let derived_accounts = governance_payload.resolve_derived_accounts(cpi_authority, message_origin)?;
+
+    let mut accounts = Vec::new();
+    for acc in governance_payload.accounts() {
+        let pubkey = if acc.pubkey == CPI_AUTHORITY_PLACEHOLDER {
+            (*cpi_authority).into()
+        } else if acc.pubkey == PAYER_PLACEHOLDER {
+            AddressLocator::Payer
+        } else if acc.pubkey == CONTEXT_PLACEHOLDER {
+            AddressLocator::Context
+        } else if acc.pubkey == ORIGIN_PLACEHOLDER {
+            (*message_origin).into()
+        } else if let Some(index) = derived_placeholder_index(&acc.pubkey) {
+            (*derived_accounts.get(index).ok_or(GovernanceError::InvalidDerivedAccount)?).into()
+        } else {
+            acc.pubkey.into()
+        };
+        accounts.push(AccountMetaRef {
+            pubkey,
+            is_writable: acc.is_writable,
+        });
+    }
+    // ALT accounts referenced by compact account lists, read by lz_receive and execute_queued
+    accounts.extend(referenced_alts.into_iter().map(|alt| AccountMetaRef {
+        pubkey: alt.into(),
+        is_writable: false,
+    }));
+    Ok(accounts)
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::*;
+use anchor_lang::system_program::{self, Transfer};
+
+/// Brings a Governance created by a previous program version to the current layout, see
+/// `Governance::migrate`, and grows the account to the current size. Accounts of the original
+/// layout can not be loaded until migrated, so it must run right after the program upgrade.
+/// Permissionless and idempotent, the payer covers the extra rent.
+#[derive(Accounts)]
+pub struct MigrateGovernance<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: deserialized in apply, as the original layout does not load as Governance
+    #[account(mut, owner = crate::ID)]
+    pub governance: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateGovernance<'_> {
+    pub fn apply(ctx: &mut Context<MigrateGovernance>) -> Result<()> {
+        let governance = &ctx.accounts.governance;
+        let (migrated, changed) = Governance::migrate(&governance.try_borrow_data()?)?;
+        realloc_account(
+            governance,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            8 + Governance::INIT_SPACE,
+        )?;
+        if changed {
+            migrated.try_serialize(&mut &mut governance.try_borrow_mut_data()?[..])?;
+        }
+        Ok(())
+    }
+}
+
+/// Grows `account` to `new_len`, with the payer topping up the rent exemption.
+pub(crate) fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+    let lamports = Rent::get()?.minimum_balance(new_len).saturating_sub(account.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer { from: payer.to_account_info(), to: account.to_account_info() },
+            ),
+            lamports,
+        )?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
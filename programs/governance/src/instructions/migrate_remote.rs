@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{instructions::migrate_governance::realloc_account, *};
+
+/// Brings a Remote created by a previous program version to the current layout, see
+/// `Remote::migrate`, turning its address into the single enabled sender. Remotes of the original
+/// layout are still trusted by `lz_receive`, but can only be updated or removed once migrated.
+/// Permissionless and idempotent, the payer covers the extra rent.
+#[derive(Accounts)]
+pub struct MigrateRemote<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: deserialized in apply, as the original layout does not load as Remote
+    #[account(mut, owner = crate::ID)]
+    pub remote: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateRemote<'_> {
+    pub fn apply(ctx: &mut Context<MigrateRemote>) -> Result<()> {
+        let remote = &ctx.accounts.remote;
+        let (migrated, changed) = Remote::migrate(&remote.try_borrow_data()?)?;
+        realloc_account(
+            remote,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            8 + Remote::INIT_SPACE,
+        )?;
+        if changed {
+            migrated.try_serialize(&mut &mut remote.try_borrow_mut_data()?[..])?;
+        }
+        Ok(())
+    }
+}
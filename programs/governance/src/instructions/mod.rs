@@ -1,14 +1,34 @@
 // SPDX-License-Identifier: Apache-2.0
+pub mod add_remote_sender;
+pub mod cancel_proposal;
+pub mod execute_queued;
+pub mod execute_queued_types;
 pub mod init_governance;
 pub mod lz_receive;
 pub mod lz_receive_types_info;
 pub mod lz_receive_types_v2;
+pub mod migrate_governance;
+pub mod migrate_remote;
+pub mod set_frozen;
+pub mod set_guardian;
+pub mod remove_remote;
+pub mod remove_remote_sender;
 pub mod set_remote;
 pub mod set_oapp_config;
 
+pub use add_remote_sender::*;
+pub use cancel_proposal::*;
+pub use execute_queued::*;
+pub use execute_queued_types::*;
 pub use init_governance::*;
 pub use lz_receive::*;
 pub use lz_receive_types_info::*;
 pub use lz_receive_types_v2::*;
+pub use migrate_governance::*;
+pub use migrate_remote::*;
+pub use set_frozen::*;
+pub use set_guardian::*;
+pub use remove_remote::*;
+pub use remove_remote_sender::*;
 pub use set_remote::*;
 pub use set_oapp_config::*;
\ No newline at end of file
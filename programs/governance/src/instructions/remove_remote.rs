@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{error::GovernanceError, events::RemoteRemoved, *};
+
+/// Disables a source chain by closing its remote. Subsequent messages from `remote_eid`, including
+/// already queued proposals, fail with `UnknownRemote` until `set_remote` is called again.
+#[derive(Accounts)]
+#[instruction(params: RemoveRemoteParams)]
+pub struct RemoveRemote<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [REMOTE_SEED, &governance.key().to_bytes(), &params.remote_eid.to_be_bytes()],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+
+    /// CHECK: receives the rent of the remote
+    #[account(mut)]
+    pub rent_recipient: AccountInfo<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()],
+        bump = governance.bump,
+        has_one = admin @GovernanceError::Unauthorized
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+impl RemoveRemote<'_> {
+    pub fn apply(_ctx: &mut Context<RemoveRemote>, params: &RemoveRemoteParams) -> Result<()> {
+        emit!(RemoteRemoved { remote_eid: params.remote_eid });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveRemoteParams {
+    pub remote_eid: u32,
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{error::GovernanceError, events::RemoteSenderRemoved, *};
+
+/// Removes a trusted sender from a remote. Its queued proposals can no longer be executed.
+#[derive(Accounts)]
+#[instruction(params: RemoveRemoteSenderParams)]
+pub struct RemoveRemoteSender<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [REMOTE_SEED, &governance.key().to_bytes(), &params.remote_eid.to_be_bytes()],
+        bump = remote.bump
+    )]
+    pub remote: Account<'info, Remote>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()],
+        bump = governance.bump,
+        has_one = admin @GovernanceError::Unauthorized
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+impl RemoveRemoteSender<'_> {
+    pub fn apply(ctx: &mut Context<RemoveRemoteSender>, params: &RemoveRemoteSenderParams) -> Result<()> {
+        let senders = &mut ctx.accounts.remote.senders;
+        let index = senders
+            .iter()
+            .position(|s| s.address == params.sender)
+            .ok_or(GovernanceError::RemoteSenderNotFound)?;
+        senders.remove(index);
+        emit!(RemoteSenderRemoved {
+            remote_eid: params.remote_eid,
+            sender: params.sender,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveRemoteSenderParams {
+    pub remote_eid: u32,
+    pub sender: [u8; 32],
+}
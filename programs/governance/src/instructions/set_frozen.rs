@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{error::GovernanceError, events::GovernanceFrozenSet, *};
+
+/// Guardian switch that blocks execution of all governance messages, both in `lz_receive` and in
+/// `execute_queued`, except for guardian rotations. Messages can still be queued while frozen.
+#[derive(Accounts)]
+pub struct SetFrozen<'info> {
+    pub guardian: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()],
+        bump = governance.bump,
+        constraint = governance.guardian == Some(guardian.key()) @GovernanceError::Unauthorized
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+impl SetFrozen<'_> {
+    pub fn apply(ctx: &mut Context<SetFrozen>, params: &SetFrozenParams) -> Result<()> {
+        ctx.accounts.governance.frozen = params.frozen;
+        emit!(GovernanceFrozenSet { frozen: params.frozen });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetFrozenParams {
+    pub frozen: bool,
+}
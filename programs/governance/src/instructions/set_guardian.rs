@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::{
+    events::{GovernanceFrozenSet, GuardianSet},
+    msg_codec::GovernancePayload,
+    *,
+};
+use anchor_lang::Discriminator;
+
+/// Rotates the guardian and lifts a freeze set by the previous one. Only callable by a CPI
+/// authority of this Governance instance, i.e. by a governance message targeting this program with
+/// `CPI_AUTHORITY_PLACEHOLDER` as the signer. Such a message is executed even while frozen, see
+/// `is_guardian_rotation`.
+#[derive(Accounts)]
+#[instruction(params: SetGuardianParams)]
+pub struct SetGuardian<'info> {
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED, &governance.key().to_bytes(), &params.src_eid.to_be_bytes(), &params.origin_caller],
+        bump
+    )]
+    pub cpi_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, &governance.id.to_be_bytes()],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+impl SetGuardian<'_> {
+    pub fn apply(ctx: &mut Context<SetGuardian>, params: &SetGuardianParams) -> Result<()> {
+        ctx.accounts.governance.guardian = params.guardian;
+        emit!(GuardianSet { guardian: params.guardian });
+        if ctx.accounts.governance.frozen {
+            ctx.accounts.governance.frozen = false;
+            emit!(GovernanceFrozenSet { frozen: false });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetGuardianParams {
+    pub src_eid: u32,
+    pub origin_caller: [u8; 32],
+    pub guardian: Option<Pubkey>,
+}
+
+/// Whether the only effect of `payload` is to call `set_guardian` on this program, the one message
+/// executed while frozen so that a lost or compromised guardian can not block governance for good.
+pub fn is_guardian_rotation(payload: &GovernancePayload) -> bool {
+    payload.value.is_none()
+        && payload.instructions.len() == 1
+        && payload.instructions[0].program_id == crate::ID
+        && payload.instructions[0].data.starts_with(&instruction::SetGuardian::DISCRIMINATOR)
+}
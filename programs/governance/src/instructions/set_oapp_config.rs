@@ -40,6 +40,12 @@ impl SetOAppConfig<'_> {
             },
             SetOAppConfigParams::LzReceiveAlts(alts) => {
                 ctx.accounts.lz_receive_types_accounts.alts = alts;
+            },
+            SetOAppConfigParams::MinDelay(min_delay) => {
+                ctx.accounts.governance.min_delay = min_delay;
+            },
+            SetOAppConfigParams::ExecutorId(executor_id) => {
+                ctx.accounts.governance.executor_id = executor_id;
             }
         }
         Ok(())
@@ -51,4 +57,6 @@ pub enum SetOAppConfigParams {
     Admin(Pubkey),
     Delegate(Pubkey), // OApp delegate for the endpoint
     LzReceiveAlts(Vec<Pubkey>),
+    MinDelay(u64), // seconds, 0 disables the execution queue
+    ExecutorId(Pubkey), // executor program deriving the execution context
 }
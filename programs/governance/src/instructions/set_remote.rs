@@ -2,6 +2,8 @@
 use crate::{error::GovernanceError, *};
 use anchor_lang::prelude::*;
 
+/// Creates the remote of `remote_eid` or resets it to a single trusted sender.
+/// Further senders are managed with `add_remote_sender` and `remove_remote_sender`.
 #[derive(Accounts)]
 #[instruction(params: SetRemoteParams)]
 pub struct SetRemote<'info> {
@@ -29,8 +31,13 @@ pub struct SetRemote<'info> {
 
 impl SetRemote<'_> {
     pub fn apply(ctx: &mut Context<SetRemote>, params: &SetRemoteParams) -> Result<()> {
-        ctx.accounts.remote.address = params.remote;
+        ctx.accounts.remote.senders = vec![RemoteSender {
+            address: params.remote,
+            enabled: true,
+        }];
         ctx.accounts.remote.bump = ctx.bumps.remote;
+        ctx.accounts.remote.strict_ordering = params.strict_ordering;
+        ctx.accounts.remote.version = REMOTE_VERSION;
         Ok(())
     }
 }
@@ -39,4 +46,5 @@ impl SetRemote<'_> {
 pub struct SetRemoteParams {
     pub remote_eid: u32,
     pub remote: [u8; 32],
+    pub strict_ordering: bool,
 }
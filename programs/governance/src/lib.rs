@@ -2,6 +2,7 @@
 pub mod instructions;
 pub mod state;
 pub mod error;
+pub mod events;
 pub mod msg_codec;
 
 use anchor_lang::prelude::*;
@@ -16,15 +17,23 @@ declare_id!(Pubkey::new_from_array(program_id_from_env!(
     "EiQujD3MpwhznKZn4jSa9J7j6cHd7W9QA213QrPZgpR3"
 )));
 
+/// LayerZero executor program, the usual value of `Governance::executor_id`
 pub const EXECUTOR_ID: Pubkey = pubkey!("6doghB248px58JSSwG4qejQ46kFMW4AMj7vzJnWZHNZn");
 
 pub const GOVERNANCE_SEED: &[u8] = b"Governance";
 pub const REMOTE_SEED: &[u8] = b"Remote";
 pub const CPI_AUTHORITY_SEED: &[u8] = b"CpiAuthority";
+pub const QUEUED_PROPOSAL_SEED: &[u8] = b"QueuedProposal";
+pub const ORIGIN_NONCE_SEED: &[u8] = b"OriginNonce";
+pub const MESSAGE_ORIGIN_SEED: &[u8] = b"MessageOrigin";
+pub const TREASURY_SEED: &[u8] = b"Treasury";
 
 pub const CPI_AUTHORITY_PLACEHOLDER: Pubkey = sentinel_pubkey(b"cpi_authority");
 pub const PAYER_PLACEHOLDER: Pubkey = sentinel_pubkey(b"payer");
 pub const CONTEXT_PLACEHOLDER: Pubkey = sentinel_pubkey(b"context");
+pub const ORIGIN_PLACEHOLDER: Pubkey = sentinel_pubkey(b"origin");
+const DERIVED_PLACEHOLDER_PREFIX: &[u8] = b"derived";
+const ALT_PLACEHOLDER_PREFIX: &[u8] = b"alt";
 
 #[program]
 pub mod governance {
@@ -37,10 +46,36 @@ pub mod governance {
         InitGovernance::apply(&mut ctx, &params)
     }
 
+    pub fn migrate_governance(mut ctx: Context<MigrateGovernance>) -> Result<()> {
+        MigrateGovernance::apply(&mut ctx)
+    }
+
+    pub fn migrate_remote(mut ctx: Context<MigrateRemote>) -> Result<()> {
+        MigrateRemote::apply(&mut ctx)
+    }
+
     pub fn set_remote(mut ctx: Context<SetRemote>, params: SetRemoteParams) -> Result<()> {
         SetRemote::apply(&mut ctx, &params)
     }
 
+    pub fn remove_remote(mut ctx: Context<RemoveRemote>, params: RemoveRemoteParams) -> Result<()> {
+        RemoveRemote::apply(&mut ctx, &params)
+    }
+
+    pub fn add_remote_sender(
+        mut ctx: Context<AddRemoteSender>,
+        params: AddRemoteSenderParams,
+    ) -> Result<()> {
+        AddRemoteSender::apply(&mut ctx, &params)
+    }
+
+    pub fn remove_remote_sender(
+        mut ctx: Context<RemoveRemoteSender>,
+        params: RemoveRemoteSenderParams,
+    ) -> Result<()> {
+        RemoveRemoteSender::apply(&mut ctx, &params)
+    }
+
     pub fn lz_receive<'info>(
         mut ctx: Context<'_, '_, '_, 'info, LzReceive<'info>>,
         params: LzReceiveParams,
@@ -48,6 +83,35 @@ pub mod governance {
         LzReceive::apply(&mut ctx, &params)
     }
 
+    pub fn execute_queued<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, ExecuteQueued<'info>>,
+        params: ExecuteQueuedParams,
+    ) -> Result<()> {
+        ExecuteQueued::apply(&mut ctx, &params)
+    }
+
+    pub fn execute_queued_types(
+        ctx: Context<ExecuteQueuedTypes>,
+        params: ExecuteQueuedParams,
+    ) -> Result<oapp::lz_receive_types_v2::LzReceiveTypesV2Result> {
+        ExecuteQueuedTypes::apply(&ctx, &params)
+    }
+
+    pub fn cancel_proposal(
+        mut ctx: Context<CancelProposal>,
+        params: CancelProposalParams,
+    ) -> Result<()> {
+        CancelProposal::apply(&mut ctx, &params)
+    }
+
+    pub fn set_frozen(mut ctx: Context<SetFrozen>, params: SetFrozenParams) -> Result<()> {
+        SetFrozen::apply(&mut ctx, &params)
+    }
+
+    pub fn set_guardian(mut ctx: Context<SetGuardian>, params: SetGuardianParams) -> Result<()> {
+        SetGuardian::apply(&mut ctx, &params)
+    }
+
     pub fn lz_receive_types_info(
         ctx: Context<LzReceiveTypesInfo>,
         params: LzReceiveParams,
@@ -71,6 +135,10 @@ pub mod governance {
 }
 
 const fn sentinel_pubkey(input: &[u8]) -> Pubkey {
+    Pubkey::new_from_array(sentinel_bytes(input))
+}
+
+const fn sentinel_bytes(input: &[u8]) -> [u8; 32] {
     let mut output: [u8; 32] = [0; 32];
 
     let mut i = 0;
@@ -79,5 +147,41 @@ const fn sentinel_pubkey(input: &[u8]) -> Pubkey {
         i += 1;
     }
 
+    output
+}
+
+/// Placeholder of the entry `index` of the derived accounts table of a governance message.
+pub const fn derived_placeholder(index: u8) -> Pubkey {
+    let mut output = sentinel_bytes(DERIVED_PLACEHOLDER_PREFIX);
+    output[31] = index;
     Pubkey::new_from_array(output)
 }
+
+/// Index in the derived accounts table if `pubkey` is a derived account placeholder.
+pub fn derived_placeholder_index(pubkey: &Pubkey) -> Option<usize> {
+    let index = pubkey.to_bytes()[31];
+    if *pubkey == derived_placeholder(index) {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+/// Placeholder of the entry `entry_index` of the ALT `alt_index` in
+/// `GovernanceLzReceiveTypesAccounts.alts`, used by compact account lists.
+pub const fn alt_placeholder(alt_index: u8, entry_index: u8) -> Pubkey {
+    let mut output = sentinel_bytes(ALT_PLACEHOLDER_PREFIX);
+    output[30] = alt_index;
+    output[31] = entry_index;
+    Pubkey::new_from_array(output)
+}
+
+/// `(alt_index, entry_index)` if `pubkey` is an ALT entry placeholder.
+pub fn alt_placeholder_indexes(pubkey: &Pubkey) -> Option<(u8, u8)> {
+    let bytes = pubkey.to_bytes();
+    if *pubkey == alt_placeholder(bytes[30], bytes[31]) {
+        Some((bytes[30], bytes[31]))
+    } else {
+        None
+    }
+}
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
-use crate::error::GovernanceError;
+use crate::{
+    alt_placeholder, alt_placeholder_indexes, derived_placeholder_index, error::GovernanceError, CONTEXT_PLACEHOLDER, CPI_AUTHORITY_PLACEHOLDER, ORIGIN_PLACEHOLDER, PAYER_PLACEHOLDER,
+};
 use anchor_lang::prelude::*;
+use solana_program::address_lookup_table::{self, state::AddressLookupTable};
 use solana_program::instruction::Instruction;
 use solana_program::pubkey::Pubkey;
 use std::io::{self, Read, Write};
@@ -47,6 +50,24 @@ impl GovernanceMessage {
 
     /// Reads ONLY the body of the message, not the header.
     pub fn read_body(reader: &mut &[u8], origin_caller: [u8; 32], program_id: Pubkey) -> io::Result<Self> {
+        let accounts = Self::read_accounts(reader)?;
+
+        Ok(Self {
+            origin_caller,
+            program_id,
+            accounts,
+            data: reader.to_vec(),
+        })
+    }
+
+    /// Writes ONLY the body of the message, not the header.
+    pub fn write_body<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.write_accounts(writer)?;
+        writer.write_all(&self.data)?;
+        Ok(())
+    }
+
+    fn read_accounts(reader: &mut &[u8]) -> io::Result<Vec<Acc>> {
         let accounts_len = Self::read_u16(reader)?;
         let mut accounts = Vec::with_capacity(accounts_len as usize);
 
@@ -61,17 +82,11 @@ impl GovernanceMessage {
             });
         }
 
-        Ok(Self {
-            origin_caller,
-            program_id,
-            accounts,
-            data: reader.to_vec(),
-        })
+        Ok(accounts)
     }
 
-    /// Writes ONLY the body of the message, not the header.
-    pub fn write_body<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        Self::write_u16(writer, self.accounts.len() as u16)?;
+    fn write_accounts<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        Self::write_u16(writer, Self::checked_u16(self.accounts.len())?)?;
 
         for acc in &self.accounts {
             Self::write_pubkey(writer, &acc.pubkey)?;
@@ -79,10 +94,69 @@ impl GovernanceMessage {
             Self::write_u8(writer, acc.is_writable as u8)?;
         }
 
-        writer.write_all(&self.data)?;
         Ok(())
     }
 
+    /// Same as [`Self::read_accounts`] with the `FLAG_COMPACT_ACCOUNTS` encoding, an account
+    /// referenced in an ALT is decoded as its `alt_placeholder`.
+    fn read_compact_accounts(reader: &mut &[u8]) -> io::Result<Vec<Acc>> {
+        let accounts_len = Self::read_u16(reader)?;
+        let mut accounts = Vec::with_capacity(accounts_len as usize);
+
+        for _ in 0..accounts_len {
+            let flags = Self::read_u8(reader)?;
+            if flags & !(COMPACT_SIGNER | COMPACT_WRITABLE | COMPACT_ALT) != 0 {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "unknown account flags"));
+            }
+            let pubkey = if flags & COMPACT_ALT != 0 {
+                let alt_index = Self::read_u8(reader)?;
+                let entry_index = Self::read_u8(reader)?;
+                alt_placeholder(alt_index, entry_index)
+            } else {
+                Self::read_pubkey(reader)?
+            };
+            accounts.push(Acc {
+                pubkey,
+                is_signer: flags & COMPACT_SIGNER != 0,
+                is_writable: flags & COMPACT_WRITABLE != 0,
+            });
+        }
+
+        Ok(accounts)
+    }
+
+    fn write_compact_accounts<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        Self::write_u16(writer, Self::checked_u16(self.accounts.len())?)?;
+
+        for acc in &self.accounts {
+            let alt_indexes = alt_placeholder_indexes(&acc.pubkey);
+            let mut flags = 0;
+            if acc.is_signer {
+                flags |= COMPACT_SIGNER;
+            }
+            if acc.is_writable {
+                flags |= COMPACT_WRITABLE;
+            }
+            if alt_indexes.is_some() {
+                flags |= COMPACT_ALT;
+            }
+            Self::write_u8(writer, flags)?;
+            match alt_indexes {
+                Some((alt_index, entry_index)) => {
+                    Self::write_u8(writer, alt_index)?;
+                    Self::write_u8(writer, entry_index)?;
+                }
+                None => Self::write_pubkey(writer, &acc.pubkey)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn checked_u16(len: usize) -> io::Result<u16> {
+        u16::try_from(len).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "length overflow"))
+    }
+
     /// Decodes ONLY the origin caller from the message.
     pub fn decode_origin_caller(message: &[u8]) -> Result<[u8; 32]> {
         let origin_caller_end = 32;
@@ -109,6 +183,12 @@ impl GovernanceMessage {
         Ok(u16::from_be_bytes(buf))
     }
 
+    fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+        let mut buf = [0u8; 8];
+        reader.read_exact(&mut buf)?;
+        Ok(u64::from_be_bytes(buf))
+    }
+
     fn read_pubkey<R: Read>(reader: &mut R) -> io::Result<Pubkey> {
         let mut buf = [0u8; 32];
         reader.read_exact(&mut buf)?;
@@ -129,6 +209,10 @@ impl GovernanceMessage {
         writer.write_all(&value.to_be_bytes())
     }
 
+    fn write_u64<W: Write>(writer: &mut W, value: u64) -> io::Result<()> {
+        writer.write_all(&value.to_be_bytes())
+    }
+
     fn write_pubkey<W: Write>(writer: &mut W, pubkey: &Pubkey) -> io::Result<()> {
         writer.write_all(&pubkey.to_bytes())
     }
@@ -138,6 +222,556 @@ impl GovernanceMessage {
     }
 }
 
+/// Marker byte opening a versioned body. A legacy body starts with a big endian `accounts_length`
+/// which can never reach `0xFF00` within the LayerZero message size.
+pub const FORMAT_MARKER: u8 = 0xFF;
+pub const FORMAT_VERSION_1: u8 = 1;
+
+/// `valid_until` (u64, unix timestamp in seconds) follows the format header
+pub const FLAG_VALID_UNTIL: u8 = 1 << 0;
+/// `nonce` (u64, per origin caller sequence number) follows the format header
+pub const FLAG_NONCE: u8 = 1 << 1;
+/// derived accounts table follows the format header, see [`DerivedAccount`]
+pub const FLAG_DERIVED_ACCOUNTS: u8 = 1 << 2;
+/// account lists use the compact encoding: `flags (1) | pubkey (32)` or, with `COMPACT_ALT`,
+/// `flags (1) | alt_index (1) | entry_index (1)` referencing `GovernanceLzReceiveTypesAccounts.alts`
+pub const FLAG_COMPACT_ACCOUNTS: u8 = 1 << 3;
+/// `value` (u64 lamports, u8 [`ValueSource`]) follows the format header
+pub const FLAG_VALUE: u8 = 1 << 4;
+
+/// Flags understood by this version of the program, any other flag is rejected.
+pub const SUPPORTED_FLAGS: u8 =
+    FLAG_VALID_UNTIL | FLAG_NONCE | FLAG_DERIVED_ACCOUNTS | FLAG_COMPACT_ACCOUNTS | FLAG_VALUE;
+
+/// Account flags of the compact encoding
+pub const COMPACT_SIGNER: u8 = 1 << 0;
+pub const COMPACT_WRITABLE: u8 = 1 << 1;
+pub const COMPACT_ALT: u8 = 1 << 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageType {
+    /// `accounts_length | accounts | data (remaining)`, same as the legacy body
+    Single = 0,
+    /// `instructions_length | (accounts_length | accounts | data_length | data) * instructions_length`
+    Batch = 1,
+}
+
+impl TryFrom<u8> for MessageType {
+    type Error = io::Error;
+
+    fn try_from(value: u8) -> io::Result<Self> {
+        match value {
+            0 => Ok(Self::Single),
+            1 => Ok(Self::Batch),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidData, "unknown message type")),
+        }
+    }
+}
+
+/// Header of a versioned body, placed right after ORIGIN_CALLER and TARGET:
+/// | field         | size (bytes) | description                                          |
+/// |---------------+--------------+------------------------------------------------------|
+/// | FORMAT_MARKER |            1 | 0xFF                                                 |
+/// | version       |            1 | Format version, only `FORMAT_VERSION_1` is supported |
+/// | message_type  |            1 | [`MessageType`]                                      |
+/// | flags         |            1 | Optional fields present in the body                  |
+///
+/// The optional fields follow the header in the order of their flag bits:
+/// | field         | size (bytes) | description                                          |
+/// |---------------+--------------+------------------------------------------------------|
+/// | valid_until   |            8 | If `FLAG_VALID_UNTIL`, message expiry timestamp      |
+/// | nonce         |            8 | If `FLAG_NONCE`, per origin caller sequence number   |
+/// | derived       |     variable | If `FLAG_DERIVED_ACCOUNTS`, see [`DerivedAccount`]   |
+/// | value         |        8 + 1 | If `FLAG_VALUE`, see [`Value`]                       |
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormatHeader {
+    pub version: u8,
+    pub message_type: MessageType,
+    pub flags: u8,
+}
+
+impl FormatHeader {
+    /// Reads the header, expects the marker byte to be the next byte of the reader.
+    /// An unknown version is reported as [`io::ErrorKind::Unsupported`].
+    pub fn read(reader: &mut &[u8]) -> io::Result<Self> {
+        if GovernanceMessage::read_u8(reader)? != FORMAT_MARKER {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "missing format marker"));
+        }
+        let version = GovernanceMessage::read_u8(reader)?;
+        if version != FORMAT_VERSION_1 {
+            return Err(io::Error::new(io::ErrorKind::Unsupported, "unsupported version"));
+        }
+        let message_type = MessageType::try_from(GovernanceMessage::read_u8(reader)?)?;
+        let flags = GovernanceMessage::read_u8(reader)?;
+        if flags & !SUPPORTED_FLAGS != 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "unknown flags"));
+        }
+
+        Ok(Self {
+            version,
+            message_type,
+            flags,
+        })
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        GovernanceMessage::write_u8(writer, FORMAT_MARKER)?;
+        GovernanceMessage::write_u8(writer, self.version)?;
+        GovernanceMessage::write_u8(writer, self.message_type as u8)?;
+        GovernanceMessage::write_u8(writer, self.flags)
+    }
+}
+
+/// Governance message carrying one or more instructions for the target program, executed in order
+/// under the same CPI authority. The body is either the legacy [`GovernanceMessage`] body, decoded
+/// as a single instruction, or a versioned body opened by a [`FormatHeader`].
+/// The wire format of a version 1 batch is:
+/// | field               |                     size (bytes) | description                         |
+/// |---------------------+----------------------------------+-------------------------------------|
+/// | ORIGIN_CALLER       |                               32 | Origin caller address as bytes32    |
+/// | TARGET              |                               32 | Target address as bytes32           |
+/// |---------------------+----------------------------------+-------------------------------------|
+/// | FORMAT_HEADER       |                                4 | 0xFF, 1, MessageType::Batch, flags  |
+/// | instructions_length |                                1 | Number of instructions              |
+/// |---------------------+----------------------------------+-------------------------------------|
+/// | accounts_length     |                                2 | Repeated for each instruction       |
+/// | accounts            | `accounts_length` * (32 + 1 + 1) |                                     |
+/// | data_length         |                                2 |                                     |
+/// | data                |                    `data_length` |                                     |
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GovernancePayload {
+    pub origin_caller: [u8; 32],
+    pub program_id: Pubkey,
+    pub instructions: Vec<GovernanceMessage>,
+    /// The message is not executed after this unix timestamp
+    pub valid_until: Option<u64>,
+    /// Sequence number of the message for its origin caller, enforced when the remote has
+    /// `strict_ordering` enabled
+    pub nonce: Option<u64>,
+    /// Accounts derived on-chain, referenced in the account lists with `derived_placeholder(index)`
+    pub derived_accounts: Vec<DerivedAccount>,
+    /// Account lists are written with the compact encoding
+    pub compact_accounts: bool,
+    /// Lamports transferred to the CPI authority before the governed instructions
+    pub value: Option<Value>,
+}
+
+impl GovernancePayload {
+    pub fn from_bytes(message: &[u8]) -> Result<Self> {
+        Self::decode(&mut &message[..]).map_err(|e| match e.kind() {
+            io::ErrorKind::Unsupported => error!(GovernanceError::UnsupportedMessageVersion),
+            _ => error!(GovernanceError::InvalidGovernanceMessage),
+        })
+    }
+
+    /// Decode a full governance message (header + body), legacy or versioned.
+    pub fn decode(reader: &mut &[u8]) -> io::Result<Self> {
+        let origin_caller = GovernanceMessage::read_bytes32(reader)?;
+        let program_id = GovernanceMessage::read_pubkey(reader)?;
+
+        Self::read_body(reader, origin_caller, program_id)
+    }
+
+    /// Encode a full governance message (header + version 1 body).
+    pub fn encode<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        GovernanceMessage::write_bytes32(writer, &self.origin_caller)?;
+        GovernanceMessage::write_pubkey(writer, &self.program_id)?;
+        self.write_body(writer)
+    }
+
+    /// Reads ONLY the body of the message, not the header.
+    pub fn read_body(reader: &mut &[u8], origin_caller: [u8; 32], program_id: Pubkey) -> io::Result<Self> {
+        if reader.first() != Some(&FORMAT_MARKER) {
+            let message = GovernanceMessage::read_body(reader, origin_caller, program_id)?;
+            return Ok(Self {
+                origin_caller,
+                program_id,
+                instructions: vec![message],
+                valid_until: None,
+                nonce: None,
+                derived_accounts: vec![],
+                compact_accounts: false,
+                value: None,
+            });
+        }
+
+        let header = FormatHeader::read(reader)?;
+        let valid_until = if header.flags & FLAG_VALID_UNTIL != 0 { Some(GovernanceMessage::read_u64(reader)?) } else { None };
+        let nonce = if header.flags & FLAG_NONCE != 0 { Some(GovernanceMessage::read_u64(reader)?) } else { None };
+        let derived_accounts = if header.flags & FLAG_DERIVED_ACCOUNTS != 0 { DerivedAccount::read_table(reader)? } else { vec![] };
+        let compact_accounts = header.flags & FLAG_COMPACT_ACCOUNTS != 0;
+        let value = if header.flags & FLAG_VALUE != 0 { Some(Value::read(reader)?) } else { None };
+        let instructions = match header.message_type {
+            MessageType::Single if compact_accounts => {
+                let accounts = GovernanceMessage::read_compact_accounts(reader)?;
+                vec![GovernanceMessage {
+                    origin_caller,
+                    program_id,
+                    accounts,
+                    data: reader.to_vec(),
+                }]
+            }
+            MessageType::Single => {
+                vec![GovernanceMessage::read_body(reader, origin_caller, program_id)?]
+            }
+            MessageType::Batch => Self::read_batch(reader, origin_caller, program_id, compact_accounts)?,
+        };
+
+        Ok(Self {
+            origin_caller,
+            program_id,
+            instructions,
+            valid_until,
+            nonce,
+            derived_accounts,
+            compact_accounts,
+            value,
+        })
+    }
+
+    /// Writes ONLY the body of the message, not the header. Always uses the version 1 format,
+    /// a single instruction is written as [`MessageType::Single`].
+    pub fn write_body<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let message_type = if self.instructions.len() == 1 { MessageType::Single } else { MessageType::Batch };
+        FormatHeader {
+            version: FORMAT_VERSION_1,
+            message_type,
+            flags: self.flags(),
+        }
+        .write(writer)?;
+        if let Some(valid_until) = self.valid_until {
+            GovernanceMessage::write_u64(writer, valid_until)?;
+        }
+        if let Some(nonce) = self.nonce {
+            GovernanceMessage::write_u64(writer, nonce)?;
+        }
+        if !self.derived_accounts.is_empty() {
+            DerivedAccount::write_table(writer, &self.derived_accounts)?;
+        }
+        if let Some(value) = &self.value {
+            value.write(writer)?;
+        }
+
+        match message_type {
+            MessageType::Single if self.compact_accounts => {
+                self.instructions[0].write_compact_accounts(writer)?;
+                writer.write_all(&self.instructions[0].data)
+            }
+            MessageType::Single => self.instructions[0].write_body(writer),
+            MessageType::Batch => self.write_batch(writer),
+        }
+    }
+
+    fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.valid_until.is_some() {
+            flags |= FLAG_VALID_UNTIL;
+        }
+        if self.nonce.is_some() {
+            flags |= FLAG_NONCE;
+        }
+        if !self.derived_accounts.is_empty() {
+            flags |= FLAG_DERIVED_ACCOUNTS;
+        }
+        if self.compact_accounts {
+            flags |= FLAG_COMPACT_ACCOUNTS;
+        }
+        if self.value.is_some() {
+            flags |= FLAG_VALUE;
+        }
+        flags
+    }
+
+    /// Replaces the ALT entry placeholders of the account lists with the addresses stored in the
+    /// ALTs. `alts` are `GovernanceLzReceiveTypesAccounts.alts`, the referenced ALT accounts are
+    /// looked up in `alt_infos`. Returns the ALT accounts which were referenced.
+    pub fn resolve_alt_accounts(&mut self, alts: &[Pubkey], alt_infos: &[AccountInfo]) -> Result<Vec<Pubkey>> {
+        let mut referenced_alts: Vec<Pubkey> = Vec::new();
+        for acc in self.instructions.iter_mut().flat_map(|ix| ix.accounts.iter_mut()) {
+            let (alt_index, entry_index) = match alt_placeholder_indexes(&acc.pubkey) {
+                Some(indexes) => indexes,
+                None => continue,
+            };
+            let alt = alts.get(alt_index as usize).ok_or(GovernanceError::InvalidAltAccount)?;
+            let alt_info = alt_infos
+                .iter()
+                .find(|info| info.key == alt)
+                .ok_or(GovernanceError::InvalidAltAccount)?;
+            require!(alt_info.owner == &address_lookup_table::program::ID, GovernanceError::InvalidAltAccount);
+
+            let data = alt_info.try_borrow_data()?;
+            let lookup_table = AddressLookupTable::deserialize(&data).map_err(|_| GovernanceError::InvalidAltAccount)?;
+            acc.pubkey = *lookup_table
+                .addresses
+                .get(entry_index as usize)
+                .ok_or(GovernanceError::InvalidAltAccount)?;
+
+            if !referenced_alts.contains(alt) {
+                referenced_alts.push(*alt);
+            }
+        }
+        Ok(referenced_alts)
+    }
+
+    /// Resolves the derived accounts table. Entries may reference the CPI authority, the message
+    /// origin and earlier entries, the payer and the execution context are not allowed as they
+    /// are not known to `lz_receive_types_v2`.
+    pub fn resolve_derived_accounts(&self, cpi_authority: &Pubkey, message_origin: &Pubkey) -> Result<Vec<Pubkey>> {
+        let mut resolved = Vec::with_capacity(self.derived_accounts.len());
+        for derived_account in &self.derived_accounts {
+            let substitute = |key: &Pubkey| -> Result<Pubkey> {
+                if *key == CPI_AUTHORITY_PLACEHOLDER {
+                    Ok(*cpi_authority)
+                } else if *key == ORIGIN_PLACEHOLDER {
+                    Ok(*message_origin)
+                } else if *key == PAYER_PLACEHOLDER || *key == CONTEXT_PLACEHOLDER {
+                    err!(GovernanceError::InvalidDerivedAccount)
+                } else if let Some(index) = derived_placeholder_index(key) {
+                    resolved.get(index).copied().ok_or(error!(GovernanceError::InvalidDerivedAccount))
+                } else {
+                    Ok(*key)
+                }
+            };
+
+            let address = match derived_account {
+                DerivedAccount::Ata { owner, mint, token_program } => {
+                    anchor_spl::associated_token::get_associated_token_address_with_program_id(
+                        &substitute(owner)?,
+                        &substitute(mint)?,
+                        &substitute(token_program)?,
+                    )
+                }
+                DerivedAccount::Pda { program_id, seeds } => {
+                    let seeds = seeds
+                        .iter()
+                        .map(|seed| match <[u8; 32]>::try_from(seed.as_slice()) {
+                            Ok(key) => substitute(&Pubkey::new_from_array(key)).map(|key| key.to_bytes().to_vec()),
+                            Err(_) => Ok(seed.clone()),
+                        })
+                        .collect::<Result<Vec<Vec<u8>>>>()?;
+                    let seeds: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+                    Pubkey::try_find_program_address(&seeds, &substitute(program_id)?)
+                        .ok_or(GovernanceError::InvalidDerivedAccount)?
+                        .0
+                }
+            };
+            resolved.push(address);
+        }
+        Ok(resolved)
+    }
+
+    /// Whether the message expired, i.e. `valid_until` is set and in the past.
+    pub fn is_expired(&self, unix_timestamp: i64) -> bool {
+        match self.valid_until {
+            Some(valid_until) => unix_timestamp > i64::try_from(valid_until).unwrap_or(i64::MAX),
+            None => false,
+        }
+    }
+
+    fn read_batch(
+        reader: &mut &[u8],
+        origin_caller: [u8; 32],
+        program_id: Pubkey,
+        compact_accounts: bool,
+    ) -> io::Result<Vec<GovernanceMessage>> {
+        let instructions_len = GovernanceMessage::read_u8(reader)?;
+        if instructions_len == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "empty batch"));
+        }
+
+        let mut instructions = Vec::with_capacity(instructions_len as usize);
+        for _ in 0..instructions_len {
+            let accounts = if compact_accounts {
+                GovernanceMessage::read_compact_accounts(reader)?
+            } else {
+                GovernanceMessage::read_accounts(reader)?
+            };
+            let data_len = GovernanceMessage::read_u16(reader)? as usize;
+            if reader.len() < data_len {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "data too short"));
+            }
+            let (data, rest) = reader.split_at(data_len);
+            *reader = rest;
+
+            instructions.push(GovernanceMessage {
+                origin_caller,
+                program_id,
+                accounts,
+                data: data.to_vec(),
+            });
+        }
+
+        if !reader.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "trailing bytes"));
+        }
+
+        Ok(instructions)
+    }
+
+    fn write_batch<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let instructions_len = u8::try_from(self.instructions.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many instructions"))?;
+        GovernanceMessage::write_u8(writer, instructions_len)?;
+
+        for instruction in &self.instructions {
+            if self.compact_accounts {
+                instruction.write_compact_accounts(writer)?;
+            } else {
+                instruction.write_accounts(writer)?;
+            }
+            GovernanceMessage::write_u16(writer, GovernanceMessage::checked_u16(instruction.data.len())?)?;
+            writer.write_all(&instruction.data)?;
+        }
+
+        Ok(())
+    }
+
+    /// Union of the accounts of all instructions, in order of first appearance. An account is
+    /// writable (signer) if it is writable (signer) in any of the instructions.
+    pub fn accounts(&self) -> Vec<Acc> {
+        let mut accounts: Vec<Acc> = Vec::new();
+        for acc in self.instructions.iter().flat_map(|ix| ix.accounts.iter()) {
+            match accounts.iter_mut().find(|a| a.pubkey == acc.pubkey) {
+                Some(existing) => {
+                    existing.is_signer |= acc.is_signer;
+                    existing.is_writable |= acc.is_writable;
+                }
+                None => accounts.push(acc.clone()),
+            }
+        }
+        accounts
+    }
+
+    /// Whether an instruction references the payer or the execution context. Both resolve to the
+    /// caller of the instruction that executes the message, which for a queued message is whoever
+    /// calls the permissionless `execute_queued`.
+    pub fn uses_caller_accounts(&self) -> bool {
+        self.instructions
+            .iter()
+            .flat_map(|ix| ix.accounts.iter())
+            .any(|acc| acc.pubkey == PAYER_PLACEHOLDER || acc.pubkey == CONTEXT_PLACEHOLDER)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueSource {
+    /// The payer of `lz_receive` (or `execute_queued`)
+    Payer = 0,
+    /// The Governance treasury PDA, seeds `[TREASURY_SEED, governance]`
+    Treasury = 1,
+}
+
+/// Lamports transferred to the CPI authority before the governed instructions are invoked,
+/// mirroring `msg.value` on the EVM receiver. The governed instructions can spend them with the
+/// CPI authority as signer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Value {
+    pub lamports: u64,
+    pub source: ValueSource,
+}
+
+impl Value {
+    fn read(reader: &mut &[u8]) -> io::Result<Self> {
+        let lamports = GovernanceMessage::read_u64(reader)?;
+        let source = match GovernanceMessage::read_u8(reader)? {
+            0 => ValueSource::Payer,
+            1 => ValueSource::Treasury,
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidData, "unknown value source")),
+        };
+        Ok(Self { lamports, source })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        GovernanceMessage::write_u64(writer, self.lamports)?;
+        GovernanceMessage::write_u8(writer, self.source as u8)
+    }
+}
+
+pub const DERIVED_ACCOUNT_ATA: u8 = 0;
+pub const DERIVED_ACCOUNT_PDA: u8 = 1;
+
+/// Account derived on-chain from the message, so that proposals don't need to precompute
+/// addresses depending on the CPI authority. Any 32 bytes input equal to `CPI_AUTHORITY_PLACEHOLDER`,
+/// `ORIGIN_PLACEHOLDER` or the placeholder of an earlier entry is substituted before derivation.
+/// The wire format of the table is:
+/// | field                   | size (bytes) | description                                   |
+/// |-------------------------+--------------+-----------------------------------------------|
+/// | derived_accounts_length |            1 | Number of entries                             |
+/// | kind                    |            1 | Repeated for each entry, 0 = ATA, 1 = PDA     |
+/// | ATA                     |      32 * 3  | owner, mint, token_program                    |
+/// | PDA                     |     variable | program_id (32), seeds_length (1),            |
+/// |                         |              | seeds as seed_length (1) + seed bytes         |
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DerivedAccount {
+    Ata {
+        owner: Pubkey,
+        mint: Pubkey,
+        token_program: Pubkey,
+    },
+    Pda {
+        program_id: Pubkey,
+        seeds: Vec<Vec<u8>>,
+    },
+}
+
+impl DerivedAccount {
+    fn read_table(reader: &mut &[u8]) -> io::Result<Vec<Self>> {
+        let len = GovernanceMessage::read_u8(reader)?;
+        let mut derived_accounts = Vec::with_capacity(len as usize);
+        for _ in 0..len {
+            let derived_account = match GovernanceMessage::read_u8(reader)? {
+                DERIVED_ACCOUNT_ATA => Self::Ata {
+                    owner: GovernanceMessage::read_pubkey(reader)?,
+                    mint: GovernanceMessage::read_pubkey(reader)?,
+                    token_program: GovernanceMessage::read_pubkey(reader)?,
+                },
+                DERIVED_ACCOUNT_PDA => {
+                    let program_id = GovernanceMessage::read_pubkey(reader)?;
+                    let seeds_len = GovernanceMessage::read_u8(reader)?;
+                    let mut seeds = Vec::with_capacity(seeds_len as usize);
+                    for _ in 0..seeds_len {
+                        let mut seed = vec![0u8; GovernanceMessage::read_u8(reader)? as usize];
+                        reader.read_exact(&mut seed)?;
+                        seeds.push(seed);
+                    }
+                    Self::Pda { program_id, seeds }
+                }
+                _ => return Err(io::Error::new(io::ErrorKind::InvalidData, "unknown derived account kind")),
+            };
+            derived_accounts.push(derived_account);
+        }
+        Ok(derived_accounts)
+    }
+
+    fn write_table<W: Write>(writer: &mut W, derived_accounts: &[Self]) -> io::Result<()> {
+        let too_long = || io::Error::new(io::ErrorKind::InvalidInput, "length overflow");
+        GovernanceMessage::write_u8(writer, u8::try_from(derived_accounts.len()).map_err(|_| too_long())?)?;
+        for derived_account in derived_accounts {
+            match derived_account {
+                Self::Ata { owner, mint, token_program } => {
+                    GovernanceMessage::write_u8(writer, DERIVED_ACCOUNT_ATA)?;
+                    GovernanceMessage::write_pubkey(writer, owner)?;
+                    GovernanceMessage::write_pubkey(writer, mint)?;
+                    GovernanceMessage::write_pubkey(writer, token_program)?;
+                }
+                Self::Pda { program_id, seeds } => {
+                    GovernanceMessage::write_u8(writer, DERIVED_ACCOUNT_PDA)?;
+                    GovernanceMessage::write_pubkey(writer, program_id)?;
+                    GovernanceMessage::write_u8(writer, u8::try_from(seeds.len()).map_err(|_| too_long())?)?;
+                    for seed in seeds {
+                        GovernanceMessage::write_u8(writer, u8::try_from(seed.len()).map_err(|_| too_long())?)?;
+                        writer.write_all(seed)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl From<GovernanceMessage> for Instruction {
     fn from(val: GovernanceMessage) -> Self {
         let GovernanceMessage {
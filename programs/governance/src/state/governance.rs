@@ -1,5 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::*;
+use anchor_lang::Discriminator;
+
+/// Layout version of Governance, bumped with migrate_governance whenever fields are appended
+pub const GOVERNANCE_VERSION: u8 = 1;
 
 #[account]
 #[derive(InitSpace)]
@@ -7,6 +11,58 @@ pub struct Governance {
     pub id: u64,
     pub admin: Pubkey,
     pub bump: u8,
+    // seconds a received message is queued before it can be executed, 0 executes it in lz_receive
+    pub min_delay: u64,
+    // can cancel queued proposals and freeze execution, rotated only through governance messages
+    pub guardian: Option<Pubkey>,
+    pub frozen: bool,
+    // executor program the execution context of CONTEXT_PLACEHOLDER is derived from
+    pub executor_id: Pubkey,
+    pub version: u8,
+}
+
+impl Governance {
+    /// Reads the data of a Governance account of any version and brings it to GOVERNANCE_VERSION,
+    /// returning whether it changed. The original layout has a fixed size and is told apart by it.
+    pub fn migrate(data: &[u8]) -> Result<(Governance, bool)> {
+        let mut governance = if data.len() == 8 + GovernanceV0::INIT_SPACE {
+            require!(
+                data[..8] == Governance::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            GovernanceV0::deserialize(&mut &data[8..])?.into_current()
+        } else {
+            Governance::try_deserialize(&mut &data[..])?
+        };
+        if governance.version == GOVERNANCE_VERSION {
+            return Ok((governance, false));
+        }
+        governance.version = GOVERNANCE_VERSION;
+        Ok((governance, true))
+    }
+}
+
+/// Governance as deployed before versioning
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct GovernanceV0 {
+    pub id: u64,
+    pub admin: Pubkey,
+    pub bump: u8,
+}
+
+impl GovernanceV0 {
+    fn into_current(self) -> Governance {
+        Governance {
+            id: self.id,
+            admin: self.admin,
+            bump: self.bump,
+            min_delay: 0,
+            guardian: None,
+            frozen: false,
+            executor_id: EXECUTOR_ID,
+            version: 0,
+        }
+    }
 }
 
 #[account]
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::*;
+
+/// Origin of the governance message being executed, seeds `[MESSAGE_ORIGIN_SEED, governance]`.
+/// It is only set for the duration of the governed instruction CPI, so governed programs can
+/// authorize by origin details by passing `ORIGIN_PLACEHOLDER`. Zeroed otherwise.
+#[account]
+#[derive(InitSpace, Default)]
+pub struct MessageOrigin {
+    pub src_eid: u32,
+    pub sender: [u8; 32],
+    pub origin_caller: [u8; 32],
+    pub guid: [u8; 32],
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl MessageOrigin {
+    pub fn clear(&mut self) {
+        *self = Self {
+            bump: self.bump,
+            ..Default::default()
+        };
+    }
+}
@@ -1,6 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 pub mod governance;
+mod message_origin;
+mod origin_nonce;
+mod queued_proposal;
 mod remote;
 
 pub use governance::*;
+pub use message_origin::*;
+pub use origin_nonce::*;
+pub use queued_proposal::*;
 pub use remote::*;
@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::*;
+
+/// Nonce of the last executed governance message of an origin caller on a remote, seeds
+/// `[ORIGIN_NONCE_SEED, governance, src_eid, origin_caller]`. Only enforced when the remote has
+/// `strict_ordering` enabled.
+#[account]
+#[derive(InitSpace)]
+pub struct OriginNonce {
+    pub nonce: u64,
+    pub bump: u8,
+}
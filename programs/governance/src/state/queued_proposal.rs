@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+use crate::*;
+
+/// A governance message received while `Governance::min_delay` is set. It is executed by
+/// `execute_queued` once `eta` has passed.
+#[account]
+pub struct QueuedProposal {
+    pub src_eid: u32,
+    pub sender: [u8; 32],
+    pub nonce: u64,
+    pub guid: [u8; 32],
+    pub eta: i64,
+    pub payer: Pubkey, // receives the rent back once the proposal is closed
+    pub bump: u8,
+    pub message: Vec<u8>,
+}
+
+impl QueuedProposal {
+    /// ETA of a message queued at `now` with the given delay, saturating at `i64::MAX`
+    pub fn eta(now: i64, min_delay: u64) -> i64 {
+        now.saturating_add(i64::try_from(min_delay).unwrap_or(i64::MAX))
+    }
+
+    /// Whether the proposal can be executed at `now`. Until then it can be cancelled by the guardian.
+    pub fn is_ready(&self, now: i64) -> bool {
+        now >= self.eta
+    }
+
+    pub fn init_space(message_len: usize) -> usize {
+        4 + 32 + 8 + 32 + 8 + 32 + 1 + 4 + message_len
+    }
+}
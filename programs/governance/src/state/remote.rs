@@ -1,9 +1,78 @@
 // SPDX-License-Identifier: Apache-2.0
-use crate::*;
+use crate::{error::GovernanceError, *};
+use anchor_lang::Discriminator;
+
+pub const MAX_REMOTE_SENDERS: usize = 4;
+
+/// Layout version of Remote, bumped with migrate_remote whenever fields are appended
+pub const REMOTE_VERSION: u8 = 1;
 
 #[account]
 #[derive(InitSpace)]
 pub struct Remote {
+    // trusted governance senders of the source eid, several while the sender is being rotated
+    #[max_len(MAX_REMOTE_SENDERS)]
+    pub senders: Vec<RemoteSender>,
+    pub bump: u8,
+    // governance messages of each origin caller must carry consecutive nonces
+    pub strict_ordering: bool,
+    pub version: u8,
+}
+
+#[derive(Clone, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoteSender {
+    pub address: [u8; 32],
+    pub enabled: bool,
+}
+
+impl Remote {
+    /// Reads the remote of a source eid, `UnknownRemote` if it was never set or has been removed.
+    /// Remotes of the original layout are read as well, so messages are received before migration.
+    pub fn load(info: &AccountInfo) -> Result<Remote> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return err!(GovernanceError::UnknownRemote);
+        }
+        let (remote, _) = Remote::migrate(&info.try_borrow_data()?)?;
+        Ok(remote)
+    }
+
+    /// Reads the data of a Remote account of any version and brings it to REMOTE_VERSION,
+    /// returning whether it changed. The original layout has a fixed size and is told apart by it,
+    /// as its address may also deserialize as the current layout.
+    pub fn migrate(data: &[u8]) -> Result<(Remote, bool)> {
+        let mut remote = if data.len() == 8 + RemoteV0::INIT_SPACE {
+            require!(data[..8] == Remote::DISCRIMINATOR, ErrorCode::AccountDiscriminatorMismatch);
+            RemoteV0::deserialize(&mut &data[8..])?.into_current()
+        } else {
+            Remote::try_deserialize(&mut &data[..])?
+        };
+        if remote.version == REMOTE_VERSION {
+            return Ok((remote, false));
+        }
+        remote.version = REMOTE_VERSION;
+        Ok((remote, true))
+    }
+
+    /// Whether `sender` is one of the enabled senders of this remote.
+    pub fn is_trusted(&self, sender: &[u8; 32]) -> bool {
+        self.senders.iter().any(|s| s.enabled && s.address == *sender)
+    }
+}
+
+/// Remote as deployed before versioning, with a single trusted sender
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct RemoteV0 {
     pub address: [u8; 32],
     pub bump: u8,
 }
+
+impl RemoteV0 {
+    fn into_current(self) -> Remote {
+        Remote {
+            senders: vec![RemoteSender { address: self.address, enabled: true }],
+            bump: self.bump,
+            strict_ordering: false,
+            version: 0,
+        }
+    }
+}
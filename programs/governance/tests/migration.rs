@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+#[cfg(test)]
+mod test_migration {
+    use anchor_lang::{prelude::*, AccountSerialize, Discriminator};
+    use governance::{
+        state::{Governance, GovernanceV0, Remote, RemoteV0, GOVERNANCE_VERSION, REMOTE_VERSION},
+        EXECUTOR_ID,
+    };
+
+    #[test]
+    fn test_migrate_governance_from_original_layout() {
+        let admin = Pubkey::new_unique();
+        let mut data = vec![0u8; 8 + GovernanceV0::INIT_SPACE];
+        data[..8].copy_from_slice(&Governance::DISCRIMINATOR);
+        GovernanceV0 { id: 0, admin, bump: 254 }.serialize(&mut &mut data[8..]).unwrap();
+
+        let (migrated, changed) = Governance::migrate(&data).unwrap();
+        assert!(changed);
+        assert_eq!(migrated.admin, admin);
+        assert_eq!(migrated.bump, 254);
+        assert_eq!(migrated.min_delay, 0);
+        assert_eq!(migrated.guardian, None);
+        assert!(!migrated.frozen);
+        assert_eq!(migrated.executor_id, EXECUTOR_ID);
+        assert_eq!(migrated.version, GOVERNANCE_VERSION);
+
+        // migrating again is a no-op
+        let mut grown = vec![0u8; 8 + Governance::INIT_SPACE];
+        migrated.try_serialize(&mut &mut grown[..]).unwrap();
+        let (again, changed) = Governance::migrate(&grown).unwrap();
+        assert!(!changed);
+        assert_eq!(again.admin, admin);
+    }
+
+    #[test]
+    fn test_migrate_remote_from_original_layout() {
+        // an EVM sender is left-padded with zeros, which also deserializes as an empty current layout
+        let mut address = [0u8; 32];
+        address[12..].copy_from_slice(&[0xab; 20]);
+        let mut data = vec![0u8; 8 + RemoteV0::INIT_SPACE];
+        data[..8].copy_from_slice(&Remote::DISCRIMINATOR);
+        RemoteV0 { address, bump: 253 }.serialize(&mut &mut data[8..]).unwrap();
+
+        let (migrated, changed) = Remote::migrate(&data).unwrap();
+        assert!(changed);
+        assert_eq!(migrated.senders.len(), 1);
+        assert_eq!(migrated.senders[0].address, address);
+        assert!(migrated.is_trusted(&address));
+        assert_eq!(migrated.bump, 253);
+        assert!(!migrated.strict_ordering);
+        assert_eq!(migrated.version, REMOTE_VERSION);
+
+        // migrating again is a no-op
+        let mut grown = vec![0u8; 8 + Remote::INIT_SPACE];
+        migrated.try_serialize(&mut &mut grown[..]).unwrap();
+        let (again, changed) = Remote::migrate(&grown).unwrap();
+        assert!(!changed);
+        assert!(again.is_trusted(&address));
+    }
+}
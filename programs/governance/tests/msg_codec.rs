@@ -12,7 +12,7 @@ mod test_msg_codec {
     use spl_token::instruction::TokenInstruction;
 
     use governance::{
-        instructions::SetOAppConfigParams, msg_codec::{Acc, GovernanceMessage}, CPI_AUTHORITY_PLACEHOLDER, CPI_AUTHORITY_SEED, GOVERNANCE_SEED, PAYER_PLACEHOLDER
+        error::GovernanceError, instructions::{is_guardian_rotation, SetGuardianParams, SetOAppConfigParams}, msg_codec::{Acc, DerivedAccount, GovernanceMessage, GovernancePayload, Value, ValueSource}, alt_placeholder, alt_placeholder_indexes, derived_placeholder, derived_placeholder_index, CPI_AUTHORITY_PLACEHOLDER, CPI_AUTHORITY_SEED, GOVERNANCE_SEED, PAYER_PLACEHOLDER
     };
     use uln::state::{ExecutorConfig, UlnConfig};
 
@@ -107,6 +107,343 @@ mod test_msg_codec {
         assert_eq!(GovernanceMessage::decode_origin_caller(&serialized).unwrap(), origin_caller);
     }
 
+    #[test]
+    fn test_governance_payload_legacy_body() {
+        let msg = GovernanceMessage {
+            origin_caller: evm_address_to_bytes32(EVM_ORIGIN_CALLER),
+            program_id: Pubkey::new_unique(),
+            accounts: vec![Acc {
+                pubkey: CPI_AUTHORITY_PLACEHOLDER,
+                is_signer: true,
+                is_writable: false,
+            }],
+            data: vec![1, 2, 3],
+        };
+        let mut serialized = Vec::new();
+        msg.encode(&mut serialized).unwrap();
+
+        let payload = GovernancePayload::from_bytes(&serialized).unwrap();
+        assert_eq!(payload.origin_caller, msg.origin_caller);
+        assert_eq!(payload.program_id, msg.program_id);
+        assert_eq!(payload.instructions, vec![msg]);
+        assert_eq!(payload.valid_until, None);
+        assert_eq!(payload.nonce, None);
+    }
+
+    #[test]
+    fn test_governance_payload_batch() {
+        let origin_caller = evm_address_to_bytes32(EVM_ORIGIN_CALLER);
+        let program_id = Pubkey::new_unique();
+        let shared = Pubkey::new_unique();
+        let payload = GovernancePayload {
+            origin_caller,
+            program_id,
+            instructions: vec![
+                GovernanceMessage {
+                    origin_caller,
+                    program_id,
+                    accounts: vec![
+                        Acc { pubkey: CPI_AUTHORITY_PLACEHOLDER, is_signer: true, is_writable: false },
+                        Acc { pubkey: shared, is_signer: false, is_writable: false },
+                    ],
+                    data: vec![1, 2, 3],
+                },
+                GovernanceMessage {
+                    origin_caller,
+                    program_id,
+                    accounts: vec![Acc { pubkey: shared, is_signer: false, is_writable: true }],
+                    data: vec![],
+                },
+            ],
+            valid_until: None,
+            nonce: None,
+            derived_accounts: vec![],
+            compact_accounts: false,
+            value: None,
+        };
+
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+        assert_eq!(GovernanceMessage::decode_origin_caller(&serialized).unwrap(), origin_caller);
+
+        assert_eq!(
+            payload.accounts(),
+            vec![
+                Acc { pubkey: CPI_AUTHORITY_PLACEHOLDER, is_signer: true, is_writable: false },
+                Acc { pubkey: shared, is_signer: false, is_writable: true },
+            ]
+        );
+
+        // trailing bytes and empty batches are rejected
+        let mut trailing = serialized.clone();
+        trailing.push(0);
+        assert!(GovernancePayload::from_bytes(&trailing).is_err());
+        let mut empty = serialized[..64].to_vec();
+        empty.extend_from_slice(&[0xFF, 1, 1, 0, 0]);
+        assert!(GovernancePayload::from_bytes(&empty).is_err());
+    }
+
+    #[test]
+    fn test_governance_payload_versioned_header() {
+        let origin_caller = evm_address_to_bytes32(EVM_ORIGIN_CALLER);
+        let program_id = Pubkey::new_unique();
+        let msg = GovernanceMessage {
+            origin_caller,
+            program_id,
+            accounts: vec![Acc { pubkey: PAYER_PLACEHOLDER, is_signer: true, is_writable: true }],
+            data: vec![9, 8, 7],
+        };
+        let payload = GovernancePayload {
+            origin_caller,
+            program_id,
+            instructions: vec![msg.clone()],
+            valid_until: None,
+            nonce: None,
+            derived_accounts: vec![],
+            compact_accounts: false,
+            value: None,
+        };
+
+        // a single instruction is encoded as version 1, message type Single
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(serialized[64..68], [0xFF, 1, 0, 0]);
+        let mut legacy = Vec::new();
+        msg.write_body(&mut legacy).unwrap();
+        assert_eq!(serialized[68..], legacy[..]);
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+
+        // unknown versions have a dedicated error
+        let mut unknown_version = serialized.clone();
+        unknown_version[65] = 2;
+        assert_eq!(
+            GovernancePayload::from_bytes(&unknown_version).unwrap_err(),
+            GovernanceError::UnsupportedMessageVersion.into()
+        );
+
+        // unknown message types and flags are invalid
+        let mut unknown_type = serialized.clone();
+        unknown_type[66] = 7;
+        assert_eq!(
+            GovernancePayload::from_bytes(&unknown_type).unwrap_err(),
+            GovernanceError::InvalidGovernanceMessage.into()
+        );
+        let mut unknown_flags = serialized.clone();
+        unknown_flags[67] = 0x80;
+        assert_eq!(
+            GovernancePayload::from_bytes(&unknown_flags).unwrap_err(),
+            GovernanceError::InvalidGovernanceMessage.into()
+        );
+    }
+
+    #[test]
+    fn test_governance_payload_optional_fields() {
+        let origin_caller = evm_address_to_bytes32(EVM_ORIGIN_CALLER);
+        let program_id = Pubkey::new_unique();
+        let mut payload = GovernancePayload {
+            origin_caller,
+            program_id,
+            instructions: vec![GovernanceMessage {
+                origin_caller,
+                program_id,
+                accounts: vec![],
+                data: vec![1],
+            }],
+            valid_until: Some(1_700_000_000),
+            nonce: Some(5),
+            derived_accounts: vec![],
+            compact_accounts: false,
+            value: None,
+        };
+
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(serialized[64..68], [0xFF, 1, 0, 0b11]);
+        assert_eq!(serialized[68..76], 1_700_000_000u64.to_be_bytes());
+        assert_eq!(serialized[76..84], 5u64.to_be_bytes());
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+
+        assert!(!payload.is_expired(1_700_000_000));
+        assert!(payload.is_expired(1_700_000_001));
+
+        // only the nonce
+        payload.valid_until = None;
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(serialized[64..68], [0xFF, 1, 0, 0b10]);
+        assert_eq!(serialized[68..76], 5u64.to_be_bytes());
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+        assert!(!payload.is_expired(i64::MAX));
+
+        // value paid by the treasury
+        payload.nonce = None;
+        payload.value = Some(Value { lamports: 1_000_000, source: ValueSource::Treasury });
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(serialized[64..68], [0xFF, 1, 0, 0b10000]);
+        assert_eq!(serialized[68..76], 1_000_000u64.to_be_bytes());
+        assert_eq!(serialized[76], 1);
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+
+        serialized[76] = 2;
+        assert!(GovernancePayload::from_bytes(&serialized).is_err());
+    }
+
+    #[test]
+    fn test_is_guardian_rotation() {
+        let origin_caller = evm_address_to_bytes32(EVM_ORIGIN_CALLER);
+        let set_guardian = governance::instruction::SetGuardian {
+            params: SetGuardianParams { src_eid: ETHEREUM_V2_MAINNET_EID, origin_caller, guardian: None },
+        };
+        let msg = GovernanceMessage {
+            origin_caller,
+            program_id: governance::id(),
+            accounts: vec![Acc { pubkey: CPI_AUTHORITY_PLACEHOLDER, is_signer: true, is_writable: false }],
+            data: anchor_lang::InstructionData::data(&set_guardian),
+        };
+        let mut payload = GovernancePayload {
+            origin_caller,
+            program_id: governance::id(),
+            instructions: vec![msg.clone()],
+            valid_until: None,
+            nonce: None,
+            derived_accounts: vec![],
+            compact_accounts: false,
+            value: None,
+        };
+        assert!(is_guardian_rotation(&payload));
+
+        // anything else in the message is blocked while frozen
+        payload.instructions.push(GovernanceMessage { data: vec![1, 2, 3], ..msg.clone() });
+        assert!(!is_guardian_rotation(&payload));
+        payload.instructions = vec![GovernanceMessage { program_id: Pubkey::new_unique(), ..msg }];
+        assert!(!is_guardian_rotation(&payload));
+    }
+
+    #[test]
+    fn test_governance_payload_derived_accounts() {
+        let origin_caller = evm_address_to_bytes32(EVM_ORIGIN_CALLER);
+        let program_id = Pubkey::new_unique();
+        let mint = pubkey!("HC8D1rWMtAifPRhUYD7PwKHMtMVLtwCjarfNVvcN3SGK");
+        let cpi_authority = get_cpi_authority();
+        let message_origin = Pubkey::new_unique();
+        let mut payload = GovernancePayload {
+            origin_caller,
+            program_id,
+            instructions: vec![GovernanceMessage {
+                origin_caller,
+                program_id,
+                accounts: vec![
+                    Acc { pubkey: derived_placeholder(0), is_signer: false, is_writable: true },
+                    Acc { pubkey: derived_placeholder(1), is_signer: false, is_writable: false },
+                ],
+                data: vec![],
+            }],
+            valid_until: None,
+            nonce: None,
+            derived_accounts: vec![
+                DerivedAccount::Ata {
+                    owner: CPI_AUTHORITY_PLACEHOLDER,
+                    mint,
+                    token_program: spl_token::id(),
+                },
+                DerivedAccount::Pda {
+                    program_id: oft::id(),
+                    seeds: vec![PEER_SEED.to_vec(), derived_placeholder(0).to_bytes().to_vec(), FUJI_EID.to_be_bytes().to_vec()],
+                },
+            ],
+            compact_accounts: false,
+            value: None,
+        };
+
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(serialized[64..68], [0xFF, 1, 0, 0b100]);
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+
+        let ata = spl_associated_token_account::get_associated_token_address(&cpi_authority, &mint);
+        let (pda, _) = Pubkey::find_program_address(&[PEER_SEED, &ata.to_bytes(), &FUJI_EID.to_be_bytes()], &oft::id());
+        assert_eq!(payload.resolve_derived_accounts(&cpi_authority, &message_origin).unwrap(), vec![ata, pda]);
+
+        assert_eq!(derived_placeholder_index(&derived_placeholder(7)), Some(7));
+        assert_eq!(derived_placeholder_index(&CPI_AUTHORITY_PLACEHOLDER), None);
+
+        // the payer is not known to lz_receive_types_v2 and can't be a derivation input
+        payload.derived_accounts[0] = DerivedAccount::Ata {
+            owner: PAYER_PLACEHOLDER,
+            mint,
+            token_program: spl_token::id(),
+        };
+        assert_eq!(
+            payload.resolve_derived_accounts(&cpi_authority, &message_origin).unwrap_err(),
+            GovernanceError::InvalidDerivedAccount.into()
+        );
+
+        // entries can only reference earlier entries
+        payload.derived_accounts.swap(0, 1);
+        assert!(payload.resolve_derived_accounts(&cpi_authority, &message_origin).is_err());
+    }
+
+    #[test]
+    fn test_governance_payload_compact_accounts() {
+        let origin_caller = evm_address_to_bytes32(EVM_ORIGIN_CALLER);
+        let program_id = Pubkey::new_unique();
+        let explicit = Pubkey::new_unique();
+        let mut payload = GovernancePayload {
+            origin_caller,
+            program_id,
+            instructions: vec![
+                GovernanceMessage {
+                    origin_caller,
+                    program_id,
+                    accounts: vec![
+                        Acc { pubkey: CPI_AUTHORITY_PLACEHOLDER, is_signer: true, is_writable: false },
+                        Acc { pubkey: alt_placeholder(1, 3), is_signer: false, is_writable: true },
+                    ],
+                    data: vec![1, 2],
+                },
+                GovernanceMessage {
+                    origin_caller,
+                    program_id,
+                    accounts: vec![Acc { pubkey: explicit, is_signer: false, is_writable: false }],
+                    data: vec![3],
+                },
+            ],
+            valid_until: None,
+            nonce: None,
+            derived_accounts: vec![],
+            compact_accounts: true,
+            value: None,
+        };
+
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(serialized[64..68], [0xFF, 1, 1, 0b1000]);
+        // instructions_length, then accounts_length and flags | pubkey of the first account
+        assert_eq!(serialized[68..72], [2, 0, 2, 0b01]);
+        assert_eq!(serialized[72..104], CPI_AUTHORITY_PLACEHOLDER.to_bytes());
+        // flags | alt_index | entry_index of the second account
+        assert_eq!(serialized[104..107], [0b110, 1, 3]);
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+
+        // single instruction
+        payload.instructions.truncate(1);
+        let mut serialized = Vec::new();
+        payload.encode(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), 64 + 4 + 2 + 33 + 3 + 2);
+        assert_eq!(GovernancePayload::from_bytes(&serialized).unwrap(), payload);
+
+        assert_eq!(alt_placeholder_indexes(&alt_placeholder(1, 3)), Some((1, 3)));
+        assert_eq!(alt_placeholder_indexes(&derived_placeholder(3)), None);
+
+        // ALT accounts must be supplied
+        assert_eq!(
+            payload.resolve_alt_accounts(&[Pubkey::new_unique()], &[]).unwrap_err(),
+            GovernanceError::InvalidAltAccount.into()
+        );
+    }
+
     #[test]
     fn test_spl_token_transfer() {
         assert_governance_program_id();
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+#[cfg(test)]
+mod test_oft_admin {
+    use anchor_lang::prelude::*;
+    use oft::instructions::governance_cpi_authority;
+
+    #[test]
+    fn test_oft_derives_the_governance_cpi_authority() {
+        // the OFT mirrors the program id and seeds of the governance program
+        assert_eq!(oft::GOVERNANCE_PROGRAM_ID, governance::ID);
+        assert_eq!(oft::GOVERNANCE_SEED, governance::GOVERNANCE_SEED);
+        assert_eq!(oft::CPI_AUTHORITY_SEED, governance::CPI_AUTHORITY_SEED);
+
+        let mut origin_caller = [0u8; 32];
+        origin_caller[12..].copy_from_slice(&[0xab; 20]);
+        for (governance_id, src_eid) in [(0u64, 30101u32), (1, 40161), (u64::MAX, u32::MAX)] {
+            // derived as the seeds of the cpi_authority account of lz_receive
+            let (governance, _) = Pubkey::find_program_address(
+                &[governance::GOVERNANCE_SEED, &governance_id.to_be_bytes()],
+                &governance::ID,
+            );
+            let (cpi_authority, _) = Pubkey::find_program_address(
+                &[governance::CPI_AUTHORITY_SEED, &governance.to_bytes(), &src_eid.to_be_bytes(), &origin_caller],
+                &governance::ID,
+            );
+            assert_eq!(governance_cpi_authority(governance_id, src_eid, &origin_caller), cpi_authority);
+        }
+
+        // any other origin caller maps to another authority
+        assert_ne!(
+            governance_cpi_authority(0, 30101, &[0xcd; 32]),
+            governance_cpi_authority(0, 30101, &origin_caller)
+        );
+    }
+}
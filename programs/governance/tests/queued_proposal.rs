@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0
+#[cfg(test)]
+mod test_queued_proposal {
+    use anchor_lang::{prelude::*, AccountDeserialize, AccountSerialize};
+    use governance::{
+        msg_codec::{Acc, GovernanceMessage, GovernancePayload},
+        state::QueuedProposal,
+        CONTEXT_PLACEHOLDER, CPI_AUTHORITY_PLACEHOLDER, PAYER_PLACEHOLDER,
+    };
+
+    fn payload(accounts: Vec<Acc>) -> GovernancePayload {
+        let origin_caller = [0xab; 32];
+        let program_id = Pubkey::new_unique();
+        GovernancePayload {
+            origin_caller,
+            program_id,
+            instructions: vec![GovernanceMessage { origin_caller, program_id, accounts, data: vec![1, 2, 3] }],
+            valid_until: None,
+            nonce: Some(1),
+            derived_accounts: vec![],
+            compact_accounts: false,
+            value: None,
+        }
+    }
+
+    fn queue(message: Vec<u8>, now: i64, min_delay: u64) -> QueuedProposal {
+        QueuedProposal {
+            src_eid: 30101,
+            sender: [0xcd; 32],
+            nonce: 7,
+            guid: [0xef; 32],
+            eta: QueuedProposal::eta(now, min_delay),
+            payer: Pubkey::new_unique(),
+            bump: 255,
+            message,
+        }
+    }
+
+    #[test]
+    fn test_queue_then_execute_or_cancel() {
+        let payload = payload(vec![Acc { pubkey: CPI_AUTHORITY_PLACEHOLDER, is_signer: true, is_writable: false }]);
+        let mut message = Vec::new();
+        payload.encode(&mut message).unwrap();
+        let queued_at = 1_700_000_000;
+        let proposal = queue(message.clone(), queued_at, 3600);
+
+        // the proposal is stored in an account sized by lz_receive
+        let mut data = vec![0u8; 8 + QueuedProposal::init_space(message.len())];
+        proposal.try_serialize(&mut &mut data[..]).unwrap();
+        let stored = QueuedProposal::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(stored.eta, queued_at + 3600);
+        assert_eq!(stored.payer, proposal.payer);
+        assert_eq!(GovernancePayload::from_bytes(&stored.message).unwrap(), payload);
+
+        // before the ETA the proposal can only be cancelled by the guardian
+        let now = queued_at + 1800;
+        assert!(!stored.is_ready(now));
+
+        // from the ETA it can only be executed
+        let now = queued_at + 3600;
+        assert!(stored.is_ready(now));
+    }
+
+    #[test]
+    fn test_eta_boundary() {
+        let proposal = queue(vec![], 1_000, 60);
+        assert_eq!(proposal.eta, 1_060);
+        assert!(!proposal.is_ready(1_059));
+        assert!(proposal.is_ready(1_060));
+        assert!(proposal.is_ready(1_061));
+
+        // a delay beyond i64 saturates instead of wrapping into the past
+        assert_eq!(QueuedProposal::eta(1_000, u64::MAX), i64::MAX);
+        assert_eq!(QueuedProposal::eta(i64::MAX - 10, 60), i64::MAX);
+        assert!(!queue(vec![], 1_000, u64::MAX).is_ready(i64::MAX - 1));
+    }
+
+    #[test]
+    fn test_caller_accounts_are_not_queueable() {
+        let governed = Pubkey::new_unique();
+        let without_caller = payload(vec![
+            Acc { pubkey: CPI_AUTHORITY_PLACEHOLDER, is_signer: true, is_writable: false },
+            Acc { pubkey: governed, is_signer: false, is_writable: true },
+        ]);
+        assert!(!without_caller.uses_caller_accounts());
+
+        for placeholder in [PAYER_PLACEHOLDER, CONTEXT_PLACEHOLDER] {
+            let mut with_caller = without_caller.clone();
+            with_caller.instructions.push(GovernanceMessage {
+                origin_caller: with_caller.origin_caller,
+                program_id: with_caller.program_id,
+                accounts: vec![Acc { pubkey: placeholder, is_signer: false, is_writable: true }],
+                data: vec![],
+            });
+            assert!(with_caller.uses_caller_accounts());
+        }
+    }
+}
@@ -1,3 +1,4 @@
 fn main() {
     println!("cargo:rerun-if-env-changed=OFT_ID");
+    println!("cargo:rerun-if-env-changed=GOVERNANCE_ID");
 }
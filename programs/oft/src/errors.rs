@@ -11,4 +11,15 @@ pub enum OFTError {
     InvalidFee,
     InvalidMintAuthority,
     Paused,
+    PeerNotBlocked,
+    InvalidGovernanceAdmin,
+    AdminLocked,
+    SupplyCapExceeded,
+    CreditCeilingExceeded,
+    InsufficientDelegatedAmount,
+    SponsorReimbursementExceeded,
+    NotPaused,
+    InvalidSponsor,
+    AccountNotMigrated,
+    NativeOnly,
 }
@@ -9,6 +9,12 @@ pub struct OFTSent {
     pub amount_received_ld: u64,
 }
 
+#[event]
+pub struct OFTReferred {
+    pub guid: [u8; 32],
+    pub referrer: Pubkey,
+}
+
 #[event]
 pub struct OFTReceived {
     pub guid: [u8; 32],
@@ -16,3 +22,37 @@ pub struct OFTReceived {
     pub to: Pubkey,
     pub amount_received_ld: u64,
 }
+
+#[event]
+pub struct AtaCreationFeeCharged {
+    pub guid: [u8; 32],
+    pub to: Pubkey,
+    pub fee_ld: u64,
+}
+
+#[event]
+pub struct PeerRemoved {
+    pub remote_eid: u32,
+    pub peer_address: [u8; 32],
+}
+
+#[event]
+pub struct ReferralClaimed {
+    pub referrer: Pubkey,
+    pub amount_ld: u64,
+}
+
+#[event]
+pub struct MintAuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Option<Pubkey>,
+}
+
+#[event]
+pub struct LocalAdminRenounced {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub governance_id: u64,
+    pub src_eid: u32,
+    pub origin_caller: [u8; 32],
+}
@@ -0,0 +1,27 @@
+use crate::*;
+
+#[derive(Accounts)]
+pub struct GetAdminStatus<'info> {
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+}
+
+impl GetAdminStatus<'_> {
+    pub fn apply(ctx: &Context<GetAdminStatus>) -> Result<AdminStatus> {
+        Ok(AdminStatus {
+            admin: ctx.accounts.oft_store.admin,
+            locked: ctx.accounts.oft_store.admin_locked,
+        })
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AdminStatus {
+    pub admin: Pubkey,
+    // the admin is a governance CPI authority, see `renounce_local_admin`
+    pub locked: bool,
+}
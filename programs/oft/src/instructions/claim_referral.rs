@@ -0,0 +1,76 @@
+use crate::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+/// Withdraws the OFT fees accrued to the referrer from the escrow.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferral<'info> {
+    pub referrer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, oft_store.key().as_ref(), referrer.key().as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Account<'info, Referral>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl ClaimReferral<'_> {
+    pub fn apply(ctx: &mut Context<ClaimReferral>) -> Result<()> {
+        let amount_ld = ctx.accounts.referral.accrued_ld;
+        ctx.accounts.referral.accrued_ld = 0;
+        ctx.accounts.oft_store.referral_accrued_ld -= amount_ld;
+
+        let seeds: &[&[u8]] = &[
+            OFT_SEED,
+            &ctx.accounts.token_escrow.key().to_bytes(),
+            &[ctx.accounts.oft_store.bump],
+        ];
+        token_interface::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.token_escrow.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.token_dest.to_account_info(),
+                    authority: ctx.accounts.oft_store.to_account_info(),
+                },
+            )
+            .with_signer(&[seeds]),
+            amount_ld,
+            ctx.accounts.token_mint.decimals,
+        )?;
+
+        emit_cpi!(ReferralClaimed {
+            referrer: ctx.accounts.referrer.key(),
+            amount_ld,
+        });
+        Ok(())
+    }
+}
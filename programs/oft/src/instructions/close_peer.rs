@@ -0,0 +1,51 @@
+use crate::*;
+
+/// Closes the config of a peer and returns its rent. Both rate limiters must be disabled (`None`)
+/// or paused (zero capacity) so that no transfer can be in flight through a live limiter.
+/// Once closed, `send` and `lz_receive` for `remote_eid` fail as the peer account is not initialized.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: ClosePeerParams)]
+pub struct ClosePeer<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [PEER_SEED, oft_store.key().as_ref(), &params.remote_eid.to_be_bytes()],
+        bump = peer.bump,
+        constraint = is_blocked(&peer.outbound_rate_limiter) @OFTError::PeerNotBlocked,
+        constraint = is_blocked(&peer.inbound_rate_limiter) @OFTError::PeerNotBlocked
+    )]
+    pub peer: Account<'info, PeerConfig>,
+    /// CHECK: receives the rent of the peer config
+    #[account(mut)]
+    pub rent_recipient: AccountInfo<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+}
+
+impl ClosePeer<'_> {
+    pub fn apply(ctx: &mut Context<ClosePeer>, params: &ClosePeerParams) -> Result<()> {
+        emit_cpi!(PeerRemoved {
+            remote_eid: params.remote_eid,
+            peer_address: ctx.accounts.peer.peer_address,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct ClosePeerParams {
+    pub remote_eid: u32,
+}
+
+fn is_blocked(rate_limiter: &Option<RateLimiter>) -> bool {
+    match rate_limiter {
+        None => true,
+        Some(rate_limiter) => rate_limiter.capacity == 0,
+    }
+}
@@ -1,5 +1,8 @@
 use crate::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use anchor_spl::{
+    token_2022::spl_token_2022::solana_program::program_option::COption,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
 use oapp::endpoint::{instructions::RegisterOAppParams, ID as ENDPOINT_ID};
 
 #[derive(Accounts)]
@@ -34,6 +37,10 @@ pub struct InitOFT<'info> {
     pub token_escrow: InterfaceAccount<'info, TokenAccount>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    /// CHECK: the current mint authority, required for Native unless it is the oft_store, so that
+    /// a multisig has its composition checked
+    #[account(constraint = token_mint.mint_authority == COption::Some(mint_authority.key()) @OFTError::InvalidMintAuthority)]
+    pub mint_authority: Option<AccountInfo<'info>>,
 }
 
 impl InitOFT<'_> {
@@ -56,6 +63,30 @@ impl InitOFT<'_> {
         ctx.accounts.oft_store.paused = false;
         ctx.accounts.oft_store.pauser = None;
         ctx.accounts.oft_store.unpauser = None;
+        ctx.accounts.oft_store.admin_locked = false;
+        ctx.accounts.oft_store.max_supply_ld = None;
+        ctx.accounts.oft_store.bridged_supply_ld = 0;
+        ctx.accounts.oft_store.sponsor_reimbursement_rate = None;
+        ctx.accounts.oft_store.referral_share_bps = 0;
+        ctx.accounts.oft_store.referral_accrued_ld = 0;
+        ctx.accounts.oft_store.version = OFT_STORE_VERSION;
+
+        // The mint authority of a Native OFT is usually a wallet handed over after init. If it is
+        // already a multisig, the store must be able to mint through it.
+        if params.oft_type == OFTType::Native {
+            if let COption::Some(mint_authority) = ctx.accounts.token_mint.mint_authority {
+                if mint_authority != ctx.accounts.oft_store.key() {
+                    let mint_authority = ctx
+                        .accounts
+                        .mint_authority
+                        .as_ref()
+                        .ok_or(OFTError::InvalidMintAuthority)?;
+                    if is_token_program_account(mint_authority) {
+                        validate_mint_authority(mint_authority, &ctx.accounts.oft_store.key())?;
+                    }
+                }
+            }
+        }
 
         // Initialize the lz_receive_types_accounts
         ctx.accounts.lz_receive_types_accounts.oft_store = ctx.accounts.oft_store.key();
@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Registers `referrer` as an integrator whose sends accrue a share of the OFT fee, see
+/// `send_referred`. Admin only, as a referrer tagging its own sends gets a fee rebate.
+#[derive(Accounts)]
+#[instruction(params: InitReferralParams)]
+pub struct InitReferral<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [REFERRAL_SEED, oft_store.key().as_ref(), params.referrer.as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    pub system_program: Program<'info, System>,
+}
+
+impl InitReferral<'_> {
+    pub fn apply(ctx: &mut Context<InitReferral>, params: &InitReferralParams) -> Result<()> {
+        ctx.accounts.referral.referrer = params.referrer;
+        ctx.accounts.referral.accrued_ld = 0;
+        ctx.accounts.referral.bump = ctx.bumps.referral;
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct InitReferralParams {
+    pub referrer: Pubkey,
+}
@@ -1,8 +1,12 @@
 use crate::*;
 use anchor_lang::solana_program;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token_2022::spl_token_2022::{self, solana_program::program_option::COption},
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
+    token_2022::spl_token_2022::{
+        self,
+        solana_program::{program_option::COption, program_pack::Pack},
+        state::Multisig,
+    },
     token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 use oapp::endpoint::{
@@ -25,13 +29,15 @@ pub struct LzReceive<'info> {
             &params.src_eid.to_be_bytes()
         ],
         bump = peer.bump,
-        constraint = peer.peer_address == params.sender @OFTError::InvalidSender
+        constraint = peer.peer_address == params.sender @OFTError::InvalidSender,
+        constraint = PeerConfig::is_current(&peer) @OFTError::AccountNotMigrated
     )]
     pub peer: Account<'info, PeerConfig>,
     #[account(
         mut,
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
-        bump = oft_store.bump
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
     )]
     pub oft_store: Account<'info, OFTStore>,
     #[account(
@@ -45,21 +51,23 @@ pub struct LzReceive<'info> {
     /// CHECK: the wallet address to receive the token
     #[account(address = Pubkey::from(msg_codec::send_to(&params.message)) @OFTError::InvalidTokenDest)]
     pub to_address: AccountInfo<'info>,
+    /// CHECK: the associated token account of to_address, created in apply if it does not exist
     #[account(
-        init_if_needed,
-        payer = payer,
-        associated_token::mint = token_mint,
-        associated_token::authority = to_address,
-        associated_token::token_program = token_program
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &to_address.key(),
+            &token_mint.key(),
+            &token_program.key()
+        ) @OFTError::InvalidTokenDest
     )]
-    pub token_dest: InterfaceAccount<'info, TokenAccount>,
+    pub token_dest: UncheckedAccount<'info>,
     #[account(
         mut,
         address = oft_store.token_mint,
         mint::token_program = token_program
     )]
     pub token_mint: InterfaceAccount<'info, Mint>,
-    // Only used for native mint, the mint authority can be:
+    // Only used for native mint, the mint authority can be (checked by validate_mint_authority):
     //      1. a spl-token multisig account with oft_store as one of the signers, and the quorum **MUST** be 1-of-n. (recommended)
     //      2. or the mint_authority is oft_store itself.
     #[account(constraint = token_mint.mint_authority == COption::Some(mint_authority.key()) @OFTError::InvalidMintAuthority)]
@@ -97,6 +105,9 @@ impl LzReceive<'_> {
         let amount_sd = msg_codec::amount_sd(&params.message);
         let mut amount_received_ld = ctx.accounts.oft_store.sd2ld(amount_sd);
 
+        // The peer cannot deliver more than was sent to it plus its allowance
+        ctx.accounts.peer.record_received(amount_sd)?;
+
         // Consume the inbound rate limiter
         ctx.accounts.peer.inbound_rate_limiter
             .as_mut()
@@ -110,8 +121,31 @@ impl LzReceive<'_> {
             }
         }
 
+        // Create the recipient token account, its rent can be charged to the recipient in tokens
+        let mut ata_creation_fee_ld = 0;
+        if ctx.accounts.token_dest.data_is_empty() {
+            associated_token::create(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: ctx.accounts.payer.to_account_info(),
+                    associated_token: ctx.accounts.token_dest.to_account_info(),
+                    authority: ctx.accounts.to_address.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ))?;
+            // the fee is waived rather than delivering nothing when the amount does not exceed it
+            if let Some(fee_ld) = ctx.accounts.peer.ata_creation_fee_ld {
+                if amount_received_ld > fee_ld {
+                    ata_creation_fee_ld = fee_ld;
+                }
+            }
+        }
+        let amount_delivered_ld = amount_received_ld - ata_creation_fee_ld;
+
         if ctx.accounts.oft_store.oft_type == OFTType::Adapter {
-            // unlock from escrow
+            // unlock from escrow, the ATA creation fee stays in the escrow as OFT fee
             ctx.accounts.oft_store.tvl_ld -= amount_received_ld;
             token_interface::transfer_checked(
                 CpiContext::new(
@@ -124,34 +158,39 @@ impl LzReceive<'_> {
                     },
                 )
                 .with_signer(&[&seeds]),
-                amount_received_ld,
+                amount_delivered_ld,
                 ctx.accounts.token_mint.decimals,
             )?;
 
             // update the amount_received_ld with the post transfer fee amount
             amount_received_ld =
-                get_post_fee_amount_ld(&ctx.accounts.token_mint, amount_received_ld)?
+                get_post_fee_amount_ld(&ctx.accounts.token_mint, amount_delivered_ld)?
         } else if let Some(mint_authority) = &ctx.accounts.mint_authority {
             // Native type
-            // mint
-            let ix = spl_token_2022::instruction::mint_to(
-                ctx.accounts.token_program.key,
-                &ctx.accounts.token_mint.key(),
-                &ctx.accounts.token_dest.key(),
-                mint_authority.key,
-                &[&ctx.accounts.oft_store.key()],
-                amount_received_ld,
-            )?;
-            solana_program::program::invoke_signed(
-                &ix,
-                &[
-                    ctx.accounts.token_dest.to_account_info(),
-                    ctx.accounts.token_mint.to_account_info(),
-                    mint_authority.to_account_info(),
-                    ctx.accounts.oft_store.to_account_info(),
-                ],
-                &[&seeds],
+            validate_mint_authority(mint_authority, &ctx.accounts.oft_store.key())?;
+            ctx.accounts.oft_store.record_minted(amount_received_ld)?;
+            mint_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.token_mint,
+                &ctx.accounts.token_dest,
+                mint_authority,
+                &ctx.accounts.oft_store,
+                seeds,
+                amount_delivered_ld,
             )?;
+            // the ATA creation fee is minted to the escrow as OFT fee
+            if ata_creation_fee_ld > 0 {
+                mint_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.token_mint,
+                    &ctx.accounts.token_escrow.to_account_info(),
+                    mint_authority,
+                    &ctx.accounts.oft_store,
+                    seeds,
+                    ata_creation_fee_ld,
+                )?;
+            }
+            amount_received_ld = amount_delivered_ld;
         } else {
             return Err(OFTError::InvalidMintAuthority.into());
         }
@@ -182,6 +221,72 @@ impl LzReceive<'_> {
             to: ctx.accounts.to_address.key(),
             amount_received_ld,
         });
+        if ata_creation_fee_ld > 0 {
+            emit_cpi!(AtaCreationFeeCharged {
+                guid: params.guid,
+                to: ctx.accounts.to_address.key(),
+                fee_ld: ata_creation_fee_ld,
+            });
+        }
         Ok(())
     }
 }
+
+/// Mints `amount_ld` to `token_dest` through the mint authority, signed by the OFT store.
+pub(crate) fn mint_tokens<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    token_mint: &InterfaceAccount<'info, Mint>,
+    token_dest: &AccountInfo<'info>,
+    mint_authority: &AccountInfo<'info>,
+    oft_store: &Account<'info, OFTStore>,
+    seeds: &[&[u8]],
+    amount_ld: u64,
+) -> Result<()> {
+    let ix = spl_token_2022::instruction::mint_to(
+        token_program.key,
+        &token_mint.key(),
+        &token_dest.key(),
+        mint_authority.key,
+        &[&oft_store.key()],
+        amount_ld,
+    )?;
+    solana_program::program::invoke_signed(
+        &ix,
+        &[
+            token_dest.to_account_info(),
+            token_mint.to_account_info(),
+            mint_authority.to_account_info(),
+            oft_store.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+    Ok(())
+}
+
+/// Checks that the store can mint alone through `mint_authority`: either the store itself or a
+/// spl-token multisig with a quorum of 1 that has the store among its signers.
+pub fn validate_mint_authority(
+    mint_authority: &AccountInfo,
+    oft_store: &Pubkey,
+) -> Result<()> {
+    if mint_authority.key() == *oft_store {
+        return Ok(());
+    }
+    if !is_token_program_account(mint_authority) {
+        msg!("mint authority {} is neither the oft_store nor a multisig", mint_authority.key());
+        return Err(OFTError::InvalidMintAuthority.into());
+    }
+    let multisig = Multisig::unpack(&mint_authority.try_borrow_data()?)
+        .map_err(|_| OFTError::InvalidMintAuthority)?;
+    let is_signer = multisig.signers[..multisig.n as usize].contains(oft_store);
+    if multisig.m != 1 || !is_signer {
+        msg!("multisig mint authority: m = {}, oft_store is signer = {}", multisig.m, is_signer);
+        return Err(OFTError::InvalidMintAuthority.into());
+    }
+    Ok(())
+}
+
+/// Whether `account` is owned by a token program, i.e. may be a spl-token multisig
+pub fn is_token_program_account(account: &AccountInfo) -> bool {
+    *account.owner == anchor_spl::token::ID || *account.owner == spl_token_2022::ID
+}
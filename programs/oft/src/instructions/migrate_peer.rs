@@ -0,0 +1,31 @@
+use crate::*;
+
+/// Brings a PeerConfig created by a previous program version to the current layout, see
+/// `PeerConfig::migrate`, and grows the account to the current size. Permissionless and
+/// idempotent, the payer covers the extra rent.
+#[derive(Accounts)]
+pub struct MigratePeer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: deserialized in apply, as the original layout may not load as PeerConfig
+    #[account(mut, owner = crate::ID)]
+    pub peer: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigratePeer<'_> {
+    pub fn apply(ctx: &mut Context<MigratePeer>) -> Result<()> {
+        let peer = &ctx.accounts.peer;
+        let (config, migrated) = PeerConfig::migrate(&peer.try_borrow_data()?)?;
+        realloc_account(
+            peer,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            8 + PeerConfig::INIT_SPACE,
+        )?;
+        if migrated {
+            config.try_serialize(&mut &mut peer.try_borrow_mut_data()?[..])?;
+        }
+        Ok(())
+    }
+}
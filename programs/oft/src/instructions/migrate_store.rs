@@ -0,0 +1,57 @@
+use crate::*;
+use anchor_lang::system_program::{self, Transfer};
+
+/// Brings an OFTStore created by a previous program version to the current layout, see
+/// `OFTStore::migrate`, and grows the account to the current size. Permissionless and
+/// idempotent, the payer covers the extra rent.
+#[derive(Accounts)]
+pub struct MigrateStore<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: deserialized in apply, as the original layout may not load as OFTStore
+    #[account(mut, owner = crate::ID)]
+    pub oft_store: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl MigrateStore<'_> {
+    pub fn apply(ctx: &mut Context<MigrateStore>) -> Result<()> {
+        let oft_store = &ctx.accounts.oft_store;
+        let (store, migrated) = OFTStore::migrate(&oft_store.try_borrow_data()?)?;
+        realloc_account(
+            oft_store,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            8 + OFTStore::INIT_SPACE,
+        )?;
+        if migrated {
+            store.try_serialize(&mut &mut oft_store.try_borrow_mut_data()?[..])?;
+        }
+        Ok(())
+    }
+}
+
+/// Grows `account` to `new_len`, with the payer topping up the rent exemption.
+pub(crate) fn realloc_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_len);
+    let lamports = rent_exempt_lamports.saturating_sub(account.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer { from: payer.to_account_info(), to: account.to_account_info() },
+            ),
+            lamports,
+        )?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
@@ -1,21 +1,47 @@
+pub mod admin_status;
+pub mod claim_referral;
+pub mod close_peer;
 pub mod init_oft;
+pub mod init_referral;
 pub mod lz_receive;
 pub mod lz_receive_types;
+pub mod migrate_peer;
+pub mod migrate_store;
 pub mod quote_oft;
 pub mod quote_send;
+pub mod quote_sponsored;
+pub mod rate_limit_capacity;
+pub mod renounce_local_admin;
 pub mod send;
+pub mod send_from;
+pub mod send_referred;
+pub mod send_sponsored;
 pub mod set_oft_config;
 pub mod set_pause;
 pub mod set_peer_config;
+pub mod transfer_mint_authority;
 pub mod withdraw_fee;
 
+pub use admin_status::*;
+pub use claim_referral::*;
+pub use close_peer::*;
 pub use init_oft::*;
+pub use init_referral::*;
 pub use lz_receive::*;
 pub use lz_receive_types::*;
+pub use migrate_peer::*;
+pub use migrate_store::*;
 pub use quote_oft::*;
 pub use quote_send::*;
+pub use quote_sponsored::*;
+pub use rate_limit_capacity::*;
+pub use renounce_local_admin::*;
 pub use send::*;
+pub use send_from::*;
+pub use send_referred::*;
+pub use send_sponsored::*;
 pub use set_oft_config::*;
 pub use set_pause::*;
 pub use set_peer_config::*;
+pub use transfer_mint_authority::*;
 pub use withdraw_fee::*;
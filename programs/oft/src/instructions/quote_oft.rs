@@ -6,7 +6,8 @@ use anchor_spl::token_interface::Mint;
 pub struct QuoteOFT<'info> {
     #[account(
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
-        bump = oft_store.bump
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
     )]
     pub oft_store: Account<'info, OFTStore>,
     #[account(
@@ -15,7 +16,8 @@ pub struct QuoteOFT<'info> {
             oft_store.key().as_ref(),
             &params.dst_eid.to_be_bytes()
         ],
-        bump = peer.bump
+        bump = peer.bump,
+        constraint = PeerConfig::is_current(&peer) @OFTError::AccountNotMigrated
     )]
     pub peer: Account<'info, PeerConfig>,
     #[account(address = oft_store.token_mint)]
@@ -65,7 +67,13 @@ impl QuoteOFT<'_> {
             });
         }
         let oft_receipt = OFTReceipt { amount_sent_ld, amount_received_ld };
-        Ok(QuoteOFTResult { oft_limits, oft_fee_details, oft_receipt })
+        let supply_headroom_ld = ctx.accounts.oft_store.supply_headroom_ld();
+        Ok(QuoteOFTResult {
+            oft_limits,
+            oft_fee_details,
+            oft_receipt,
+            supply_headroom_ld,
+        })
     }
 }
 
@@ -85,6 +93,8 @@ pub struct QuoteOFTResult {
     pub oft_limits: OFTLimits,
     pub oft_fee_details: Vec<OFTFeeDetail>,
     pub oft_receipt: OFTReceipt,
+    // Native only: amount that can still be minted on this chain, None if uncapped
+    pub supply_headroom_ld: Option<u64>,
 }
 
 /// Details about a specific fee component in OFT operations.
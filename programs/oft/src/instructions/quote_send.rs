@@ -17,7 +17,8 @@ use anchor_spl::{
 pub struct QuoteSend<'info> {
     #[account(
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
-        bump = oft_store.bump
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
     )]
     pub oft_store: Account<'info, OFTStore>,
     #[account(
@@ -26,7 +27,8 @@ pub struct QuoteSend<'info> {
             oft_store.key().as_ref(),
             &params.dst_eid.to_be_bytes()
         ],
-        bump = peer.bump
+        bump = peer.bump,
+        constraint = PeerConfig::is_current(&peer) @OFTError::AccountNotMigrated
     )]
     pub peer: Account<'info, PeerConfig>,
     #[account(address = oft_store.token_mint)]
@@ -0,0 +1,24 @@
+use crate::*;
+
+/// Tokens `send_sponsored` moves from the sender to the escrow for a sponsored native fee.
+#[derive(Accounts)]
+pub struct QuoteSponsored<'info> {
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+}
+
+impl QuoteSponsored<'_> {
+    pub fn apply(ctx: &Context<QuoteSponsored>, params: &QuoteSponsoredParams) -> Result<u64> {
+        ctx.accounts.oft_store.sponsor_reimbursement_ld(params.native_fee)
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct QuoteSponsoredParams {
+    // native fee quoted by `quote_send`, paid by the sponsor
+    pub native_fee: u64,
+}
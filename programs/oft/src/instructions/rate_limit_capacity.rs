@@ -0,0 +1,57 @@
+use crate::*;
+
+/// Views over the rate limiters of a peer, refilled to the current clock without being persisted.
+#[derive(Accounts)]
+#[instruction(params: GetRateLimitCapacityParams)]
+pub struct GetRateLimitCapacity<'info> {
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        seeds = [PEER_SEED, oft_store.key().as_ref(), &params.remote_eid.to_be_bytes()],
+        bump = peer.bump
+    )]
+    pub peer: Account<'info, PeerConfig>,
+}
+
+impl GetRateLimitCapacity<'_> {
+    pub fn amount_can_be_sent(ctx: &Context<GetRateLimitCapacity>) -> Result<RateLimitCapacity> {
+        let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        Ok(RateLimitCapacity::of(&ctx.accounts.peer.outbound_rate_limiter, current_time))
+    }
+
+    pub fn amount_can_be_received(
+        ctx: &Context<GetRateLimitCapacity>,
+    ) -> Result<RateLimitCapacity> {
+        let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        Ok(RateLimitCapacity::of(&ctx.accounts.peer.inbound_rate_limiter, current_time))
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct GetRateLimitCapacityParams {
+    pub remote_eid: u32,
+}
+
+/// Capacities in local decimals, both are 0 without a rate limiter as the direction is then blocked
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RateLimitCapacity {
+    pub available_capacity: u64,
+    pub capacity: u64,
+}
+
+impl RateLimitCapacity {
+    /// Capacities of `rate_limiter` refilled up to `current_time`, the rate limiter is left untouched
+    pub fn of(rate_limiter: &Option<RateLimiter>, current_time: u64) -> Self {
+        match rate_limiter {
+            None => Self { available_capacity: 0, capacity: 0 },
+            Some(rate_limiter) => {
+                let mut rate_limiter = rate_limiter.clone();
+                rate_limiter.refill_at(0, current_time);
+                Self { available_capacity: rate_limiter.available_capacity, capacity: rate_limiter.capacity }
+            },
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::*;
+
+/// Hands the admin over to a governance CPI authority, i.e. to Governance Messages sent by
+/// `origin_caller` from `src_eid` through the Governance instance `governance_id`, and locks it:
+/// from then on the admin can only be moved to another governance CPI authority with this
+/// instruction, `SetOFTConfigParams::Admin` is rejected.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RenounceLocalAdmin<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+}
+
+impl RenounceLocalAdmin<'_> {
+    pub fn apply(ctx: &mut Context<RenounceLocalAdmin>, params: &RenounceLocalAdminParams) -> Result<()> {
+        let cpi_authority =
+            governance_cpi_authority(params.governance_id, params.src_eid, &params.origin_caller);
+        require!(params.new_admin == cpi_authority, OFTError::InvalidGovernanceAdmin);
+
+        let previous_admin = ctx.accounts.oft_store.admin;
+        ctx.accounts.oft_store.admin = params.new_admin;
+        ctx.accounts.oft_store.admin_locked = true;
+
+        emit_cpi!(LocalAdminRenounced {
+            previous_admin,
+            new_admin: params.new_admin,
+            governance_id: params.governance_id,
+            src_eid: params.src_eid,
+            origin_caller: params.origin_caller,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RenounceLocalAdminParams {
+    pub new_admin: Pubkey,
+    pub governance_id: u64,
+    pub src_eid: u32,
+    pub origin_caller: [u8; 32],
+}
+
+/// CPI authority PDA the governance program signs with for messages of `origin_caller` from `src_eid`.
+pub fn governance_cpi_authority(governance_id: u64, src_eid: u32, origin_caller: &[u8; 32]) -> Pubkey {
+    let (governance, _) = Pubkey::find_program_address(
+        &[GOVERNANCE_SEED, &governance_id.to_be_bytes()],
+        &GOVERNANCE_PROGRAM_ID,
+    );
+    let (cpi_authority, _) = Pubkey::find_program_address(
+        &[CPI_AUTHORITY_SEED, governance.as_ref(), &src_eid.to_be_bytes(), origin_caller],
+        &GOVERNANCE_PROGRAM_ID,
+    );
+    cpi_authority
+}
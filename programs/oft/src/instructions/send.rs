@@ -16,13 +16,15 @@ pub struct Send<'info> {
             oft_store.key().as_ref(),
             &params.dst_eid.to_be_bytes()
         ],
-        bump = peer.bump
+        bump = peer.bump,
+        constraint = PeerConfig::is_current(&peer) @OFTError::AccountNotMigrated
     )]
     pub peer: Account<'info, PeerConfig>,
     #[account(
         mut,
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
-        bump = oft_store.bump
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
     )]
     pub oft_store: Account<'info, OFTStore>,
     #[account(
@@ -49,121 +51,178 @@ pub struct Send<'info> {
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-impl Send<'_> {
+impl<'info> Send<'info> {
     pub fn apply(
-        ctx: &mut Context<Send>,
+        ctx: &mut Context<'_, '_, '_, 'info, Self>,
         params: &SendParams,
     ) -> Result<(MessagingReceipt, OFTReceipt)> {
-        require!(!ctx.accounts.oft_store.paused, OFTError::Paused);
-
-        let (amount_sent_ld, amount_received_ld, oft_fee_ld) = compute_fee_and_adjust_amount(
-            params.amount_ld,
-            &ctx.accounts.oft_store,
-            &ctx.accounts.token_mint,
-            ctx.accounts.peer.fee_bps,
+        let (msg_receipt, oft_receipt) = send_tokens(
+            SendAccounts {
+                authority: &ctx.accounts.signer,
+                peer: &mut ctx.accounts.peer,
+                oft_store: &mut ctx.accounts.oft_store,
+                token_source: &ctx.accounts.token_source,
+                token_escrow: &ctx.accounts.token_escrow,
+                token_mint: &ctx.accounts.token_mint,
+                token_program: &ctx.accounts.token_program,
+                referral: None,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            params,
         )?;
-        require!(amount_received_ld >= params.min_amount_ld, OFTError::SlippageExceeded);
 
-        if let Some(rate_limiter) = ctx.accounts.peer.outbound_rate_limiter.as_mut() {
-            rate_limiter.try_consume(amount_received_ld)?;
-        } else {
-            return Err(error!(OFTError::RateLimitExceeded))
-        }
+        emit_cpi!(OFTSent {
+            guid: msg_receipt.guid,
+            dst_eid: params.dst_eid,
+            from: ctx.accounts.token_source.key(),
+            amount_sent_ld: oft_receipt.amount_sent_ld,
+            amount_received_ld: oft_receipt.amount_received_ld
+        });
+
+        Ok((msg_receipt, oft_receipt))
+    }
+}
 
-        if let Some(rate_limiter) = ctx.accounts.peer.inbound_rate_limiter.as_mut() {
-            if rate_limiter.rate_limiter_type == RateLimiterType::Net {
-                rate_limiter.refill(amount_received_ld)?;
-            }
+/// Accounts shared by the send instructions. `authority` is the owner or the delegate of
+/// `token_source`, `referral` is only supplied by `send_referred`.
+pub(crate) struct SendAccounts<'a, 'info> {
+    pub authority: &'a Signer<'info>,
+    pub peer: &'a mut Account<'info, PeerConfig>,
+    pub oft_store: &'a mut Account<'info, OFTStore>,
+    pub token_source: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_escrow: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+    pub referral: Option<&'a mut Account<'info, Referral>>,
+    pub remaining_accounts: &'a [AccountInfo<'info>],
+}
+
+/// Debits `token_source` (lock for Adapter, burn for Native) and sends the OFT message with
+/// `authority` as the compose sender.
+pub(crate) fn send_tokens(
+    accounts: SendAccounts,
+    params: &SendParams,
+) -> Result<(MessagingReceipt, OFTReceipt)> {
+    require!(!accounts.oft_store.paused, OFTError::Paused);
+
+    let (amount_sent_ld, amount_received_ld, oft_fee_ld) = compute_fee_and_adjust_amount(
+        params.amount_ld,
+        accounts.oft_store,
+        accounts.token_mint,
+        accounts.peer.fee_bps,
+    )?;
+    require!(amount_received_ld >= params.min_amount_ld, OFTError::SlippageExceeded);
+
+    // the fee stays in the escrow, the referrer share is only earmarked
+    if let Some(referral) = accounts.referral {
+        let share_ld = (oft_fee_ld as u128 * accounts.oft_store.referral_share_bps as u128
+            / MAX_FEE_BASIS_POINTS as u128) as u64;
+        referral.accrued_ld += share_ld;
+        accounts.oft_store.referral_accrued_ld += share_ld;
+    }
+
+    if accounts.authority.key() != accounts.token_source.owner {
+        require!(
+            accounts.token_source.delegated_amount >= amount_sent_ld,
+            OFTError::InsufficientDelegatedAmount
+        );
+    }
+
+    if let Some(rate_limiter) = accounts.peer.outbound_rate_limiter.as_mut() {
+        rate_limiter.try_consume(amount_received_ld)?;
+    } else {
+        return Err(error!(OFTError::RateLimitExceeded))
+    }
+
+    if let Some(rate_limiter) = accounts.peer.inbound_rate_limiter.as_mut() {
+        if rate_limiter.rate_limiter_type == RateLimiterType::Net {
+            rate_limiter.refill(amount_received_ld)?;
         }
+    }
 
-        if ctx.accounts.oft_store.oft_type == OFTType::Adapter {
-            // transfer all tokens to escrow with fee
-            ctx.accounts.oft_store.tvl_ld += amount_received_ld;
+    if accounts.oft_store.oft_type == OFTType::Adapter {
+        // transfer all tokens to escrow with fee
+        accounts.oft_store.tvl_ld += amount_received_ld;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.token_source.to_account_info(),
+                    mint: accounts.token_mint.to_account_info(),
+                    to: accounts.token_escrow.to_account_info(),
+                    authority: accounts.authority.to_account_info(),
+                },
+            ),
+            amount_sent_ld,
+            accounts.token_mint.decimals,
+        )?;
+    } else {
+        // Native type
+        // tokens minted before the OFT was deployed can be burned too
+        accounts.oft_store.bridged_supply_ld =
+            accounts.oft_store.bridged_supply_ld.saturating_sub(amount_sent_ld - oft_fee_ld);
+
+        // burn
+        token_interface::burn(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                Burn {
+                    mint: accounts.token_mint.to_account_info(),
+                    from: accounts.token_source.to_account_info(),
+                    authority: accounts.authority.to_account_info(),
+                },
+            ),
+            amount_sent_ld - oft_fee_ld,
+        )?;
+
+        // transfer fee to escrow
+        // NOTE: For Native (mint-and-burn) OFTs with fee-on-transfer tokens,
+        // the actual received OFT fee in the escrow may be less than `oft_fee_ld`
+        // due to transfer fees applied during this transfer operation.
+        if oft_fee_ld > 0 {
             token_interface::transfer_checked(
                 CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
+                    accounts.token_program.to_account_info(),
                     TransferChecked {
-                        from: ctx.accounts.token_source.to_account_info(),
-                        mint: ctx.accounts.token_mint.to_account_info(),
-                        to: ctx.accounts.token_escrow.to_account_info(),
-                        authority: ctx.accounts.signer.to_account_info(),
+                        from: accounts.token_source.to_account_info(),
+                        mint: accounts.token_mint.to_account_info(),
+                        to: accounts.token_escrow.to_account_info(),
+                        authority: accounts.authority.to_account_info(),
                     },
                 ),
-                amount_sent_ld,
-                ctx.accounts.token_mint.decimals,
+                oft_fee_ld,
+                accounts.token_mint.decimals,
             )?;
-        } else {
-            // Native type
-            // burn
-            token_interface::burn(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    Burn {
-                        mint: ctx.accounts.token_mint.to_account_info(),
-                        from: ctx.accounts.token_source.to_account_info(),
-                        authority: ctx.accounts.signer.to_account_info(),
-                    },
-                ),
-                amount_sent_ld - oft_fee_ld,
-            )?;
-
-            // transfer fee to escrow
-            // NOTE: For Native (mint-and-burn) OFTs with fee-on-transfer tokens,
-            // the actual received OFT fee in the escrow may be less than `oft_fee_ld`
-            // due to transfer fees applied during this transfer operation.
-            if oft_fee_ld > 0 {
-                token_interface::transfer_checked(
-                    CpiContext::new(
-                        ctx.accounts.token_program.to_account_info(),
-                        TransferChecked {
-                            from: ctx.accounts.token_source.to_account_info(),
-                            mint: ctx.accounts.token_mint.to_account_info(),
-                            to: ctx.accounts.token_escrow.to_account_info(),
-                            authority: ctx.accounts.signer.to_account_info(),
-                        },
-                    ),
-                    oft_fee_ld,
-                    ctx.accounts.token_mint.decimals,
-                )?;
-            }
         }
+    }
 
-        // send message to endpoint
-        let amount_sd = ctx.accounts.oft_store.ld2sd(amount_received_ld);
-        let msg_receipt = oapp::endpoint_cpi::send(
-            ctx.accounts.oft_store.endpoint_program,
-            ctx.accounts.oft_store.key(),
-            ctx.remaining_accounts,
-            &[OFT_SEED, ctx.accounts.token_escrow.key().as_ref(), &[ctx.accounts.oft_store.bump]],
-            EndpointSendParams {
-                dst_eid: params.dst_eid,
-                receiver: ctx.accounts.peer.peer_address,
-                message: msg_codec::encode(
-                    params.to,
-                    amount_sd,
-                    ctx.accounts.signer.key(),
-                    &params.compose_msg,
-                ),
-                options: ctx
-                    .accounts
-                    .peer
-                    .enforced_options
-                    .combine_options(&params.compose_msg, &params.options)?,
-                native_fee: params.native_fee,
-                lz_token_fee: params.lz_token_fee,
-            },
-        )?;
-
-        emit_cpi!(OFTSent {
-            guid: msg_receipt.guid,
+    // send message to endpoint
+    let amount_sd = accounts.oft_store.ld2sd(amount_received_ld);
+    accounts.peer.record_sent(amount_sd);
+    let msg_receipt = oapp::endpoint_cpi::send(
+        accounts.oft_store.endpoint_program,
+        accounts.oft_store.key(),
+        accounts.remaining_accounts,
+        &[OFT_SEED, accounts.token_escrow.key().as_ref(), &[accounts.oft_store.bump]],
+        EndpointSendParams {
             dst_eid: params.dst_eid,
-            from: ctx.accounts.token_source.key(),
-            amount_sent_ld,
-            amount_received_ld
-        });
+            receiver: accounts.peer.peer_address,
+            message: msg_codec::encode(
+                params.to,
+                amount_sd,
+                accounts.authority.key(),
+                &params.compose_msg,
+            ),
+            options: accounts
+                .peer
+                .enforced_options
+                .combine_options(&params.compose_msg, &params.options)?,
+            native_fee: params.native_fee,
+            lz_token_fee: params.lz_token_fee,
+        },
+    )?;
 
-        Ok((msg_receipt, OFTReceipt { amount_sent_ld, amount_received_ld }))
-    }
+    Ok((msg_receipt, OFTReceipt { amount_sent_ld, amount_received_ld }))
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
@@ -0,0 +1,89 @@
+use crate::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::solana_program::program_option::COption,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+use oapp::endpoint::MessagingReceipt;
+
+/// Same as `send`, debiting `token_source` through the delegate approval of `signer`.
+/// The delegate is the compose sender, so that an approval cannot be used to send compose
+/// messages in the name of the owner. `OFTSent.from` is `token_source`, as for `send`.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SendParams)]
+pub struct SendFrom<'info> {
+    /// delegate of token_source, pays the messaging fee
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            PEER_SEED,
+            oft_store.key().as_ref(),
+            &params.dst_eid.to_be_bytes()
+        ],
+        bump = peer.bump,
+        constraint = PeerConfig::is_current(&peer) @OFTError::AccountNotMigrated
+    )]
+    pub peer: Account<'info, PeerConfig>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::token_program = token_program,
+        constraint = token_source.delegate == COption::Some(signer.key()) @OFTError::Unauthorized
+    )]
+    pub token_source: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store.key(),
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SendFrom<'info> {
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, Self>,
+        params: &SendParams,
+    ) -> Result<(MessagingReceipt, OFTReceipt)> {
+        let (msg_receipt, oft_receipt) = send_tokens(
+            SendAccounts {
+                authority: &ctx.accounts.signer,
+                peer: &mut ctx.accounts.peer,
+                oft_store: &mut ctx.accounts.oft_store,
+                token_source: &ctx.accounts.token_source,
+                token_escrow: &ctx.accounts.token_escrow,
+                token_mint: &ctx.accounts.token_mint,
+                token_program: &ctx.accounts.token_program,
+                referral: None,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            params,
+        )?;
+
+        emit_cpi!(OFTSent {
+            guid: msg_receipt.guid,
+            dst_eid: params.dst_eid,
+            from: ctx.accounts.token_source.key(),
+            amount_sent_ld: oft_receipt.amount_sent_ld,
+            amount_received_ld: oft_receipt.amount_received_ld
+        });
+
+        Ok((msg_receipt, oft_receipt))
+    }
+}
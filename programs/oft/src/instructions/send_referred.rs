@@ -0,0 +1,94 @@
+use crate::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use oapp::endpoint::MessagingReceipt;
+
+/// Same as `send`, attributing the send to the integrator of `referral`, which accrues
+/// `referral_share_bps` of the OFT fee. `send` is left untouched for existing integrations.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SendParams)]
+pub struct SendReferred<'info> {
+    pub signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            PEER_SEED,
+            oft_store.key().as_ref(),
+            &params.dst_eid.to_be_bytes()
+        ],
+        bump = peer.bump,
+        constraint = PeerConfig::is_current(&peer) @OFTError::AccountNotMigrated
+    )]
+    pub peer: Account<'info, PeerConfig>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        token::authority = signer,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_source: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store.key(),
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, oft_store.key().as_ref(), referral.referrer.as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Account<'info, Referral>,
+}
+
+impl<'info> SendReferred<'info> {
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, Self>,
+        params: &SendParams,
+    ) -> Result<(MessagingReceipt, OFTReceipt)> {
+        let (msg_receipt, oft_receipt) = send_tokens(
+            SendAccounts {
+                authority: &ctx.accounts.signer,
+                peer: &mut ctx.accounts.peer,
+                oft_store: &mut ctx.accounts.oft_store,
+                token_source: &ctx.accounts.token_source,
+                token_escrow: &ctx.accounts.token_escrow,
+                token_mint: &ctx.accounts.token_mint,
+                token_program: &ctx.accounts.token_program,
+                referral: Some(&mut ctx.accounts.referral),
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            params,
+        )?;
+
+        emit_cpi!(OFTSent {
+            guid: msg_receipt.guid,
+            dst_eid: params.dst_eid,
+            from: ctx.accounts.token_source.key(),
+            amount_sent_ld: oft_receipt.amount_sent_ld,
+            amount_received_ld: oft_receipt.amount_received_ld
+        });
+        emit_cpi!(OFTReferred {
+            guid: msg_receipt.guid,
+            referrer: ctx.accounts.referral.referrer
+        });
+
+        Ok((msg_receipt, oft_receipt))
+    }
+}
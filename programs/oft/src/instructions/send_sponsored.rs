@@ -0,0 +1,130 @@
+use crate::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use oapp::endpoint::MessagingReceipt;
+
+/// Same as `send`, with the LayerZero native fee paid by `sponsor`, which must be the payer of the
+/// endpoint send accounts. When `OFTStore.sponsor_reimbursement_rate` is set, the native fee
+/// charged is reimbursed in tokens, moved from `token_source` to the escrow fee bucket.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(params: SendSponsoredParams)]
+pub struct SendSponsored<'info> {
+    pub signer: Signer<'info>,
+    /// pays the messaging fee
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            PEER_SEED,
+            oft_store.key().as_ref(),
+            &params.send.dst_eid.to_be_bytes()
+        ],
+        bump = peer.bump,
+        constraint = PeerConfig::is_current(&peer) @OFTError::AccountNotMigrated
+    )]
+    pub peer: Account<'info, PeerConfig>,
+    #[account(
+        mut,
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        token::authority = signer,
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_source: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = oft_store.token_escrow,
+        token::authority = oft_store.key(),
+        token::mint = token_mint,
+        token::token_program = token_program
+    )]
+    pub token_escrow: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SendSponsored<'info> {
+    pub fn apply(
+        ctx: &mut Context<'_, '_, '_, 'info, Self>,
+        params: &SendSponsoredParams,
+    ) -> Result<(MessagingReceipt, OFTReceipt)> {
+        validate_sponsor(&ctx.accounts.sponsor.key(), ctx.remaining_accounts)?;
+
+        let (msg_receipt, oft_receipt) = send_tokens(
+            SendAccounts {
+                authority: &ctx.accounts.signer,
+                peer: &mut ctx.accounts.peer,
+                oft_store: &mut ctx.accounts.oft_store,
+                token_source: &ctx.accounts.token_source,
+                token_escrow: &ctx.accounts.token_escrow,
+                token_mint: &ctx.accounts.token_mint,
+                token_program: &ctx.accounts.token_program,
+                referral: None,
+                remaining_accounts: ctx.remaining_accounts,
+            },
+            &params.send,
+        )?;
+
+        let reimbursement_ld =
+            ctx.accounts.oft_store.sponsor_reimbursement_ld(msg_receipt.fee.native_fee)?;
+        require!(
+            reimbursement_ld <= params.max_reimbursement_ld,
+            OFTError::SponsorReimbursementExceeded
+        );
+        if reimbursement_ld > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.token_source.to_account_info(),
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        to: ctx.accounts.token_escrow.to_account_info(),
+                        authority: ctx.accounts.signer.to_account_info(),
+                    },
+                ),
+                reimbursement_ld,
+                ctx.accounts.token_mint.decimals,
+            )?;
+        }
+
+        emit_cpi!(OFTSent {
+            guid: msg_receipt.guid,
+            dst_eid: params.send.dst_eid,
+            from: ctx.accounts.token_source.key(),
+            amount_sent_ld: oft_receipt.amount_sent_ld,
+            amount_received_ld: oft_receipt.amount_received_ld
+        });
+
+        Ok((msg_receipt, oft_receipt))
+    }
+}
+
+/// Checks that `sponsor` is the only signer among the endpoint send accounts. The endpoint payer
+/// is the only signer whatever the message library, so the native fee can only be charged to it.
+pub fn validate_sponsor(sponsor: &Pubkey, endpoint_accounts: &[AccountInfo]) -> Result<()> {
+    let mut payers = endpoint_accounts.iter().filter(|account| account.is_signer).peekable();
+    require!(
+        payers.peek().is_some() && payers.all(|account| account.key() == *sponsor),
+        OFTError::InvalidSponsor
+    );
+    Ok(())
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SendSponsoredParams {
+    pub send: SendParams,
+    // slippage protection on the token reimbursement of the sponsor
+    pub max_reimbursement_ld: u64,
+}
@@ -8,7 +8,8 @@ pub struct SetOFTConfig<'info> {
         mut,
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
         bump = oft_store.bump,
-        has_one = admin @OFTError::Unauthorized
+        has_one = admin @OFTError::Unauthorized,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
     )]
     pub oft_store: Account<'info, OFTStore>,
 }
@@ -17,6 +18,7 @@ impl SetOFTConfig<'_> {
     pub fn apply(ctx: &mut Context<SetOFTConfig>, params: &SetOFTConfigParams) -> Result<()> {
         match params.clone() {
             SetOFTConfigParams::Admin(admin) => {
+                require!(!ctx.accounts.oft_store.admin_locked, OFTError::AdminLocked);
                 ctx.accounts.oft_store.admin = admin;
             },
             SetOFTConfigParams::Delegate(delegate) => {
@@ -44,6 +46,16 @@ impl SetOFTConfig<'_> {
             SetOFTConfigParams::Unpauser(unpauser) => {
                 ctx.accounts.oft_store.unpauser = unpauser;
             },
+            SetOFTConfigParams::MaxSupply(max_supply_ld) => {
+                ctx.accounts.oft_store.set_max_supply(max_supply_ld)?;
+            },
+            SetOFTConfigParams::SponsorReimbursementRate(rate) => {
+                ctx.accounts.oft_store.sponsor_reimbursement_rate = rate;
+            },
+            SetOFTConfigParams::ReferralShare(share_bps) => {
+                require!(share_bps <= MAX_FEE_BASIS_POINTS, OFTError::InvalidFee);
+                ctx.accounts.oft_store.referral_share_bps = share_bps;
+            },
         }
         Ok(())
     }
@@ -57,4 +69,7 @@ pub enum SetOFTConfigParams {
     Paused(bool),
     Pauser(Option<Pubkey>),
     Unpauser(Option<Pubkey>),
+    MaxSupply(Option<u64>), // Native only, cap on the supply minted by lz_receive
+    SponsorReimbursementRate(Option<u64>), // tokens (local decimals) per SOL of sponsored native fee
+    ReferralShare(u16), // basis points of the OFT fee accrued to referrers
 }
@@ -10,13 +10,16 @@ pub struct SetPeerConfig<'info> {
         payer = admin,
         space = 8 + PeerConfig::INIT_SPACE,
         seeds = [PEER_SEED, oft_store.key().as_ref(), &params.remote_eid.to_be_bytes()],
-        bump
+        bump,
+        // a new peer is created at the current size and stamped in apply
+        constraint = peer.to_account_info().data_len() >= 8 + PeerConfig::INIT_SPACE @OFTError::AccountNotMigrated
     )]
     pub peer: Account<'info, PeerConfig>,
     #[account(
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
         bump = oft_store.bump,
-        has_one = admin @OFTError::Unauthorized
+        has_one = admin @OFTError::Unauthorized,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
     )]
     pub oft_store: Account<'info, OFTStore>,
     pub system_program: Program<'info, System>,
@@ -55,8 +58,15 @@ impl SetPeerConfig<'_> {
                     &rate_limit_params,
                 )?;
             }
+            PeerConfigParam::CreditCeiling(credit_ceiling_sd) => {
+                ctx.accounts.peer.credit_ceiling_sd = credit_ceiling_sd;
+            }
+            PeerConfigParam::AtaCreationFee(ata_creation_fee_ld) => {
+                ctx.accounts.peer.ata_creation_fee_ld = ata_creation_fee_ld;
+            }
         }
         ctx.accounts.peer.bump = ctx.bumps.peer;
+        ctx.accounts.peer.version = PEER_CONFIG_VERSION;
         Ok(())
     }
 
@@ -99,6 +109,8 @@ pub enum PeerConfigParam {
     },
     OutboundRateLimit(Option<RateLimitParams>),
     InboundRateLimit(Option<RateLimitParams>),
+    CreditCeiling(Option<u64>), // allowance in shared decimals, None disables the credit ceiling
+    AtaCreationFee(Option<u64>), // tokens charged to recipients whose token account is created
 }
 
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
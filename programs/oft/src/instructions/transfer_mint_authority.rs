@@ -0,0 +1,91 @@
+use crate::*;
+use anchor_lang::solana_program;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self, instruction::AuthorityType, solana_program::program_option::COption,
+    },
+    token_interface::{Mint, TokenInterface},
+};
+
+/// Hands the mint authority of a Native OFT over to `new_authority`, e.g. when migrating to a new
+/// program version. The store must be paused so that no receive can mint in the meantime.
+/// If the mint authority is a 1-of-n multisig containing the store, the store signs for the
+/// multisig and the authority of the whole multisig is transferred, as spl-token multisigs can not
+/// be edited once initialized.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferMintAuthority<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
+        bump = oft_store.bump,
+        has_one = admin @OFTError::Unauthorized,
+        constraint = oft_store.paused @OFTError::NotPaused,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
+    )]
+    pub oft_store: Account<'info, OFTStore>,
+    #[account(
+        mut,
+        address = oft_store.token_mint,
+        mint::token_program = token_program
+    )]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: the current mint authority, either the oft_store or a multisig containing it
+    #[account(constraint = token_mint.mint_authority == COption::Some(mint_authority.key()) @OFTError::InvalidMintAuthority)]
+    pub mint_authority: AccountInfo<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl TransferMintAuthority<'_> {
+    pub fn apply(
+        ctx: &mut Context<TransferMintAuthority>,
+        params: &TransferMintAuthorityParams,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.oft_store.oft_type == OFTType::Native,
+            OFTError::InvalidMintAuthority
+        );
+        let oft_store_key = ctx.accounts.oft_store.key();
+        validate_mint_authority(&ctx.accounts.mint_authority, &oft_store_key)?;
+        // the store signs directly as the mint authority or as a signer of the multisig
+        let signer_pubkeys = if ctx.accounts.mint_authority.key() == oft_store_key {
+            vec![]
+        } else {
+            vec![&oft_store_key]
+        };
+        let ix = spl_token_2022::instruction::set_authority(
+            ctx.accounts.token_program.key,
+            &ctx.accounts.token_mint.key(),
+            params.new_authority.as_ref(),
+            AuthorityType::MintTokens,
+            ctx.accounts.mint_authority.key,
+            &signer_pubkeys,
+        )?;
+        let seeds: &[&[u8]] = &[
+            OFT_SEED,
+            ctx.accounts.oft_store.token_escrow.as_ref(),
+            &[ctx.accounts.oft_store.bump],
+        ];
+        solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.token_mint.to_account_info(),
+                ctx.accounts.mint_authority.to_account_info(),
+                ctx.accounts.oft_store.to_account_info(),
+            ],
+            &[seeds],
+        )?;
+
+        emit_cpi!(MintAuthorityTransferred {
+            previous_authority: ctx.accounts.mint_authority.key(),
+            new_authority: params.new_authority,
+        });
+        Ok(())
+    }
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TransferMintAuthorityParams {
+    /// None disables minting for good
+    pub new_authority: Option<Pubkey>,
+}
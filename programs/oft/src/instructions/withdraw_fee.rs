@@ -7,7 +7,8 @@ pub struct WithdrawFee<'info> {
     #[account(
         seeds = [OFT_SEED, oft_store.token_escrow.as_ref()],
         bump = oft_store.bump,
-        has_one = admin @OFTError::Unauthorized
+        has_one = admin @OFTError::Unauthorized,
+        constraint = OFTStore::is_current(&oft_store) @OFTError::AccountNotMigrated
     )]
     pub oft_store: Account<'info, OFTStore>,
     #[account(
@@ -34,10 +35,15 @@ pub struct WithdrawFee<'info> {
 
 impl WithdrawFee<'_> {
     pub fn apply(ctx: &mut Context<WithdrawFee>, params: &WithdrawFeeParams) -> Result<()> {
-        require!(
-            ctx.accounts.token_escrow.amount - ctx.accounts.oft_store.tvl_ld >= params.fee_ld,
-            OFTError::InvalidFee
-        );
+        // with a fee-on-transfer mint the escrow can hold less than the referral shares accrued
+        let fee_balance_ld = ctx
+            .accounts
+            .token_escrow
+            .amount
+            .checked_sub(ctx.accounts.oft_store.tvl_ld)
+            .and_then(|amount| amount.checked_sub(ctx.accounts.oft_store.referral_accrued_ld))
+            .ok_or(OFTError::InvalidFee)?;
+        require!(fee_balance_ld >= params.fee_ld, OFTError::InvalidFee);
         let seeds: &[&[u8]] = &[
             OFT_SEED,
             &ctx.accounts.token_escrow.key().to_bytes(),
@@ -22,10 +22,21 @@ declare_id!(Pubkey::new_from_array(program_id_from_env!(
     "9UovNrJD8pQyBLheeHNayuG1wJSEAoxkmM14vw5gcsTT"
 )));
 
+/// Governance program whose CPI authorities can hold a locked admin, see `renounce_local_admin`
+pub const GOVERNANCE_PROGRAM_ID: Pubkey = Pubkey::new_from_array(program_id_from_env!(
+    "GOVERNANCE_ID",
+    "EiQujD3MpwhznKZn4jSa9J7j6cHd7W9QA213QrPZgpR3"
+));
+
 pub const OFT_SEED: &[u8] = b"OFT";
 pub const PEER_SEED: &[u8] = b"Peer";
 pub const ENFORCED_OPTIONS_SEED: &[u8] = b"EnforcedOptions";
+pub const REFERRAL_SEED: &[u8] = b"Referral";
 pub const LZ_RECEIVE_TYPES_SEED: &[u8] = oapp::LZ_RECEIVE_TYPES_SEED;
+// Seeds of the Governance and CPI authority PDAs of the governance program, copied from
+// programs/governance/src/lib.rs and only used to derive its CPI authorities
+pub const GOVERNANCE_SEED: &[u8] = b"Governance";
+pub const CPI_AUTHORITY_SEED: &[u8] = b"CpiAuthority";
 
 #[program]
 pub mod oft {
@@ -47,6 +58,13 @@ pub mod oft {
         SetOFTConfig::apply(&mut ctx, &params)
     }
 
+    pub fn renounce_local_admin(
+        mut ctx: Context<RenounceLocalAdmin>,
+        params: RenounceLocalAdminParams,
+    ) -> Result<()> {
+        RenounceLocalAdmin::apply(&mut ctx, &params)
+    }
+
     pub fn set_peer_config(
         mut ctx: Context<SetPeerConfig>,
         params: SetPeerConfigParams,
@@ -54,6 +72,10 @@ pub mod oft {
         SetPeerConfig::apply(&mut ctx, &params)
     }
 
+    pub fn close_peer(mut ctx: Context<ClosePeer>, params: ClosePeerParams) -> Result<()> {
+        ClosePeer::apply(&mut ctx, &params)
+    }
+
     pub fn set_pause(mut ctx: Context<SetPause>, params: SetPauseParams) -> Result<()> {
         SetPause::apply(&mut ctx, &params)
     }
@@ -62,8 +84,35 @@ pub mod oft {
         WithdrawFee::apply(&mut ctx, &params)
     }
 
+    pub fn transfer_mint_authority(
+        mut ctx: Context<TransferMintAuthority>,
+        params: TransferMintAuthorityParams,
+    ) -> Result<()> {
+        TransferMintAuthority::apply(&mut ctx, &params)
+    }
+
+    pub fn init_referral(mut ctx: Context<InitReferral>, params: InitReferralParams) -> Result<()> {
+        InitReferral::apply(&mut ctx, &params)
+    }
+
     // ============================== Public ==============================
 
+    pub fn claim_referral(mut ctx: Context<ClaimReferral>) -> Result<()> {
+        ClaimReferral::apply(&mut ctx)
+    }
+
+    pub fn migrate_store(mut ctx: Context<MigrateStore>) -> Result<()> {
+        MigrateStore::apply(&mut ctx)
+    }
+
+    pub fn migrate_peer(mut ctx: Context<MigratePeer>) -> Result<()> {
+        MigratePeer::apply(&mut ctx)
+    }
+
+    pub fn get_admin_status(ctx: Context<GetAdminStatus>) -> Result<AdminStatus> {
+        GetAdminStatus::apply(&ctx)
+    }
+
     pub fn quote_oft(ctx: Context<QuoteOFT>, params: QuoteOFTParams) -> Result<QuoteOFTResult> {
         QuoteOFT::apply(&ctx, &params)
     }
@@ -72,13 +121,55 @@ pub mod oft {
         QuoteSend::apply(&ctx, &params)
     }
 
-    pub fn send(
-        mut ctx: Context<Send>,
+    pub fn quote_sponsored(
+        ctx: Context<QuoteSponsored>,
+        params: QuoteSponsoredParams,
+    ) -> Result<u64> {
+        QuoteSponsored::apply(&ctx, &params)
+    }
+
+    pub fn get_amount_can_be_sent(
+        ctx: Context<GetRateLimitCapacity>,
+        _params: GetRateLimitCapacityParams,
+    ) -> Result<RateLimitCapacity> {
+        GetRateLimitCapacity::amount_can_be_sent(&ctx)
+    }
+
+    pub fn get_amount_can_be_received(
+        ctx: Context<GetRateLimitCapacity>,
+        _params: GetRateLimitCapacityParams,
+    ) -> Result<RateLimitCapacity> {
+        GetRateLimitCapacity::amount_can_be_received(&ctx)
+    }
+
+    pub fn send<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, Send<'info>>,
         params: SendParams,
     ) -> Result<(MessagingReceipt, OFTReceipt)> {
         Send::apply(&mut ctx, &params)
     }
 
+    pub fn send_from<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, SendFrom<'info>>,
+        params: SendParams,
+    ) -> Result<(MessagingReceipt, OFTReceipt)> {
+        SendFrom::apply(&mut ctx, &params)
+    }
+
+    pub fn send_referred<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, SendReferred<'info>>,
+        params: SendParams,
+    ) -> Result<(MessagingReceipt, OFTReceipt)> {
+        SendReferred::apply(&mut ctx, &params)
+    }
+
+    pub fn send_sponsored<'info>(
+        mut ctx: Context<'_, '_, '_, 'info, SendSponsored<'info>>,
+        params: SendSponsoredParams,
+    ) -> Result<(MessagingReceipt, OFTReceipt)> {
+        SendSponsored::apply(&mut ctx, &params)
+    }
+
     pub fn lz_receive(mut ctx: Context<LzReceive>, params: LzReceiveParams) -> Result<()> {
         LzReceive::apply(&mut ctx, &params)
     }
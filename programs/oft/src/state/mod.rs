@@ -1,5 +1,7 @@
 pub mod oft;
 pub mod peer_config;
+pub mod referral;
 
 pub use oft::*;
 pub use peer_config::*;
+pub use referral::*;
@@ -1,4 +1,8 @@
 use crate::*;
+use anchor_lang::{solana_program::native_token::LAMPORTS_PER_SOL, Discriminator};
+
+/// Layout version of OFTStore, bumped with migrate_store whenever fields are appended
+pub const OFT_STORE_VERSION: u8 = 1;
 
 #[account]
 #[derive(InitSpace)]
@@ -18,6 +22,19 @@ pub struct OFTStore {
     pub paused: bool,
     pub pauser: Option<Pubkey>,
     pub unpauser: Option<Pubkey>,
+    // the admin is a governance CPI authority and can only be moved to another one
+    pub admin_locked: bool,
+    // Native only: cap on bridged_supply_ld, None is uncapped
+    pub max_supply_ld: Option<u64>,
+    // Native only: minted by lz_receive minus burned by send
+    pub bridged_supply_ld: u64,
+    // tokens (local decimals) per SOL deducted from sponsored sends, None disables reimbursement
+    pub sponsor_reimbursement_rate: Option<u64>,
+    // share of oft_fee_ld accrued to the referrer of a send, in basis points
+    pub referral_share_bps: u16,
+    // accrued to referrers and not yet claimed, excluded from withdraw_fee
+    pub referral_accrued_ld: u64,
+    pub version: u8,
 }
 
 #[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq, Eq)]
@@ -38,6 +55,119 @@ impl OFTStore {
     pub fn remove_dust(&self, amount_ld: u64) -> u64 {
         amount_ld - amount_ld % self.ld2sd_rate
     }
+
+    /// Tokens owed to the sponsor of a send for paying `native_fee` lamports, rounded up.
+    pub fn sponsor_reimbursement_ld(&self, native_fee: u64) -> Result<u64> {
+        match self.sponsor_reimbursement_rate {
+            None => Ok(0),
+            Some(rate) => {
+                let reimbursement_ld =
+                    (native_fee as u128 * rate as u128).div_ceil(LAMPORTS_PER_SOL as u128);
+                u64::try_from(reimbursement_ld).map_err(|_| error!(OFTError::SponsorReimbursementExceeded))
+            },
+        }
+    }
+
+    /// Sets the cap on the supply minted by lz_receive, which only applies to a Native OFT.
+    pub fn set_max_supply(&mut self, max_supply_ld: Option<u64>) -> Result<()> {
+        require!(self.oft_type == OFTType::Native, OFTError::NativeOnly);
+        self.max_supply_ld = max_supply_ld;
+        Ok(())
+    }
+
+    /// Accounts for `amount_ld` minted by the OFT, rejecting it beyond `max_supply_ld`.
+    pub fn record_minted(&mut self, amount_ld: u64) -> Result<()> {
+        let bridged_supply_ld =
+            self.bridged_supply_ld.checked_add(amount_ld).ok_or(OFTError::SupplyCapExceeded)?;
+        if let Some(max_supply_ld) = self.max_supply_ld {
+            require!(bridged_supply_ld <= max_supply_ld, OFTError::SupplyCapExceeded);
+        }
+        self.bridged_supply_ld = bridged_supply_ld;
+        Ok(())
+    }
+
+    /// Amount that can still be minted before reaching `max_supply_ld`, None if uncapped.
+    pub fn supply_headroom_ld(&self) -> Option<u64> {
+        if self.oft_type == OFTType::Adapter {
+            return None;
+        }
+        self.max_supply_ld.map(|max_supply_ld| max_supply_ld.saturating_sub(self.bridged_supply_ld))
+    }
+}
+
+impl OFTStore {
+    /// Reads the data of an OFTStore account of any version and brings it to OFT_STORE_VERSION,
+    /// returning whether it changed. An account smaller than the current size has the original
+    /// layout, whose padding can hold leftover bytes of shrunk options: only the original fields
+    /// are read and the appended ones get their defaults.
+    pub fn migrate(data: &[u8]) -> Result<(OFTStore, bool)> {
+        let mut store = if data.len() < 8 + OFTStore::INIT_SPACE {
+            require!(
+                data.len() >= 8 && data[..8] == OFTStore::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            OFTStoreV0::deserialize(&mut &data[8..])?.into_current()
+        } else {
+            OFTStore::try_deserialize(&mut &data[..])?
+        };
+        if store.version == OFT_STORE_VERSION {
+            return Ok((store, false));
+        }
+        store.version = OFT_STORE_VERSION;
+        Ok((store, true))
+    }
+}
+
+impl OFTStore {
+    /// Whether `oft_store` has the current layout. An account of a previous version can load with
+    /// appended fields read from leftover bytes, so it must go through `migrate_store` first.
+    pub fn is_current(oft_store: &Account<OFTStore>) -> bool {
+        oft_store.version == OFT_STORE_VERSION
+            && oft_store.to_account_info().data_len() >= 8 + OFTStore::INIT_SPACE
+    }
+}
+
+/// OFTStore as deployed before versioning
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct OFTStoreV0 {
+    pub oft_type: OFTType,
+    pub ld2sd_rate: u64,
+    pub token_mint: Pubkey,
+    pub token_escrow: Pubkey,
+    pub endpoint_program: Pubkey,
+    pub bump: u8,
+    pub tvl_ld: u64,
+    pub admin: Pubkey,
+    pub default_fee_bps: u16,
+    pub paused: bool,
+    pub pauser: Option<Pubkey>,
+    pub unpauser: Option<Pubkey>,
+}
+
+impl OFTStoreV0 {
+    fn into_current(self) -> OFTStore {
+        OFTStore {
+            oft_type: self.oft_type,
+            ld2sd_rate: self.ld2sd_rate,
+            token_mint: self.token_mint,
+            token_escrow: self.token_escrow,
+            endpoint_program: self.endpoint_program,
+            bump: self.bump,
+            tvl_ld: self.tvl_ld,
+            admin: self.admin,
+            default_fee_bps: self.default_fee_bps,
+            paused: self.paused,
+            pauser: self.pauser,
+            unpauser: self.unpauser,
+            admin_locked: false,
+            max_supply_ld: None,
+            bridged_supply_ld: 0,
+            sponsor_reimbursement_rate: None,
+            referral_share_bps: 0,
+            referral_accrued_ld: 0,
+            version: 0,
+        }
+    }
 }
 
 /// LzReceiveTypesAccounts includes accounts that are used in the LzReceiveTypes
@@ -1,8 +1,12 @@
 use crate::*;
+use anchor_lang::Discriminator;
 
 pub const ENFORCED_OPTIONS_SEND_MAX_LEN: usize = 512;
 pub const ENFORCED_OPTIONS_SEND_AND_CALL_MAX_LEN: usize = 1024;
 
+/// Layout version of PeerConfig, bumped with migrate_peer whenever fields are appended
+pub const PEER_CONFIG_VERSION: u8 = 1;
+
 #[account]
 #[derive(InitSpace)]
 pub struct PeerConfig {
@@ -12,6 +16,92 @@ pub struct PeerConfig {
     pub inbound_rate_limiter: Option<RateLimiter>,
     pub fee_bps: Option<u16>,
     pub bump: u8,
+    // net flow ledger with the peer, in shared decimals
+    pub total_sent_sd: u64,
+    pub total_received_sd: u64,
+    // when set, the peer can deliver at most total_sent_sd plus this allowance (shared decimals)
+    pub credit_ceiling_sd: Option<u64>,
+    // tokens deducted in lz_receive when it creates the recipient token account, None disables it.
+    // Waived for receives that do not exceed it.
+    pub ata_creation_fee_ld: Option<u64>,
+    pub version: u8,
+}
+
+impl PeerConfig {
+    /// Records an inbound transfer, rejecting it if it exceeds the credit ceiling.
+    pub fn record_received(&mut self, amount_sd: u64) -> Result<()> {
+        let total_received_sd = self.total_received_sd.saturating_add(amount_sd);
+        if let Some(credit_ceiling_sd) = self.credit_ceiling_sd {
+            require!(
+                total_received_sd <= self.total_sent_sd.saturating_add(credit_ceiling_sd),
+                OFTError::CreditCeilingExceeded
+            );
+        }
+        self.total_received_sd = total_received_sd;
+        Ok(())
+    }
+
+    pub fn record_sent(&mut self, amount_sd: u64) {
+        self.total_sent_sd = self.total_sent_sd.saturating_add(amount_sd);
+    }
+}
+
+impl PeerConfig {
+    /// Reads the data of a PeerConfig account of any version and brings it to
+    /// PEER_CONFIG_VERSION, returning whether it changed, see `OFTStore::migrate`.
+    pub fn migrate(data: &[u8]) -> Result<(PeerConfig, bool)> {
+        let mut config = if data.len() < 8 + PeerConfig::INIT_SPACE {
+            require!(
+                data.len() >= 8 && data[..8] == PeerConfig::DISCRIMINATOR,
+                ErrorCode::AccountDiscriminatorMismatch
+            );
+            PeerConfigV0::deserialize(&mut &data[8..])?.into_current()
+        } else {
+            PeerConfig::try_deserialize(&mut &data[..])?
+        };
+        if config.version == PEER_CONFIG_VERSION {
+            return Ok((config, false));
+        }
+        config.version = PEER_CONFIG_VERSION;
+        Ok((config, true))
+    }
+}
+
+impl PeerConfig {
+    /// Whether `peer` has the current layout, see `OFTStore::is_current`.
+    pub fn is_current(peer: &Account<PeerConfig>) -> bool {
+        peer.version == PEER_CONFIG_VERSION
+            && peer.to_account_info().data_len() >= 8 + PeerConfig::INIT_SPACE
+    }
+}
+
+/// PeerConfig as deployed before versioning
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct PeerConfigV0 {
+    pub peer_address: [u8; 32],
+    pub enforced_options: EnforcedOptions,
+    pub outbound_rate_limiter: Option<RateLimiter>,
+    pub inbound_rate_limiter: Option<RateLimiter>,
+    pub fee_bps: Option<u16>,
+    pub bump: u8,
+}
+
+impl PeerConfigV0 {
+    fn into_current(self) -> PeerConfig {
+        PeerConfig {
+            peer_address: self.peer_address,
+            enforced_options: self.enforced_options,
+            outbound_rate_limiter: self.outbound_rate_limiter,
+            inbound_rate_limiter: self.inbound_rate_limiter,
+            fee_bps: self.fee_bps,
+            bump: self.bump,
+            total_sent_sd: 0,
+            total_received_sd: 0,
+            credit_ceiling_sd: None,
+            ata_creation_fee_ld: None,
+            version: 0,
+        }
+    }
 }
 
 #[derive(Clone, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize, InitSpace)]
@@ -45,8 +135,14 @@ impl RateLimiter {
     }
 
     pub fn refill(&mut self, extra_available_capacity: u64) -> Result<()> {
-        let mut new_available_capacity = extra_available_capacity;
         let current_time: u64 = Clock::get()?.unix_timestamp.try_into().unwrap();
+        self.refill_at(extra_available_capacity, current_time);
+        Ok(())
+    }
+
+    /// Same as `refill` with `current_time` as the clock
+    pub fn refill_at(&mut self, extra_available_capacity: u64, current_time: u64) {
+        let mut new_available_capacity = extra_available_capacity;
         if current_time > self.last_refill_time {
             let time_elapsed_in_seconds = current_time - self.last_refill_time;
             new_available_capacity = new_available_capacity
@@ -55,7 +151,6 @@ impl RateLimiter {
         self.available_capacity = std::cmp::min(self.capacity, self.available_capacity.saturating_add(new_available_capacity));
 
         self.last_refill_time = current_time;
-        Ok(())
     }
 
     pub fn try_consume(&mut self, amount: u64) -> Result<()> {
@@ -0,0 +1,10 @@
+use crate::*;
+
+/// Share of the OFT fees of the sends tagged with `referrer`, held in the escrow until claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct Referral {
+    pub referrer: Pubkey,
+    pub accrued_ld: u64,
+    pub bump: u8,
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod test_migration {
+    use anchor_lang::{prelude::*, AccountDeserialize, AccountSerialize, Discriminator};
+    use oft::state::{
+        EnforcedOptions, OFTStore, OFTStoreV0, OFTType, PeerConfig, PeerConfigV0, RateLimiter,
+        RateLimiterType, ENFORCED_OPTIONS_SEND_AND_CALL_MAX_LEN, ENFORCED_OPTIONS_SEND_MAX_LEN,
+        OFT_STORE_VERSION, PEER_CONFIG_VERSION,
+    };
+
+    fn legacy_peer(
+        enforced_options: EnforcedOptions,
+        rate_limiter: Option<RateLimiter>,
+    ) -> Vec<u8> {
+        let legacy = PeerConfigV0 {
+            peer_address: [1; 32],
+            enforced_options,
+            outbound_rate_limiter: rate_limiter.clone(),
+            inbound_rate_limiter: rate_limiter,
+            fee_bps: Some(10),
+            bump: 255,
+        };
+        let mut data = vec![0u8; 8 + PeerConfigV0::INIT_SPACE];
+        data[..8].copy_from_slice(&PeerConfig::DISCRIMINATOR);
+        legacy.serialize(&mut &mut data[8..]).unwrap();
+        data
+    }
+
+    fn rate_limiter(value: u64) -> RateLimiter {
+        RateLimiter {
+            capacity: value,
+            available_capacity: value,
+            refill_per_second: value,
+            last_refill_time: value,
+            rate_limiter_type: RateLimiterType::Gross,
+        }
+    }
+
+    #[test]
+    fn test_migrate_peer_ignores_leftover_bytes_of_legacy_account() {
+        // rate limiters set then removed leave their bytes in the padding of the legacy account
+        let mut data = legacy_peer(
+            EnforcedOptions {
+                send: vec![1; 10],
+                send_and_call: vec![1; 20],
+            },
+            Some(rate_limiter(1)),
+        );
+        let shrunk = PeerConfigV0 {
+            peer_address: [1; 32],
+            enforced_options: EnforcedOptions {
+                send: vec![1; 10],
+                send_and_call: vec![],
+            },
+            outbound_rate_limiter: None,
+            inbound_rate_limiter: None,
+            fee_bps: Some(10),
+            bump: 255,
+        };
+        shrunk.serialize(&mut &mut data[8..]).unwrap();
+
+        // the leftover bytes load as appended fields of the current layout
+        let dirty = PeerConfig::try_deserialize(&mut &data[..]).unwrap();
+        assert!(dirty.credit_ceiling_sd.is_some() || dirty.total_sent_sd != 0);
+
+        let (migrated, changed) = PeerConfig::migrate(&data).unwrap();
+        assert!(changed);
+        assert_eq!(migrated.peer_address, [1; 32]);
+        assert_eq!(migrated.enforced_options.send, vec![1; 10]);
+        assert!(migrated.enforced_options.send_and_call.is_empty());
+        assert!(migrated.outbound_rate_limiter.is_none());
+        assert!(migrated.inbound_rate_limiter.is_none());
+        assert_eq!(migrated.fee_bps, Some(10));
+        assert_eq!(migrated.total_sent_sd, 0);
+        assert_eq!(migrated.total_received_sd, 0);
+        assert_eq!(migrated.credit_ceiling_sd, None);
+        assert_eq!(migrated.ata_creation_fee_ld, None);
+        assert_eq!(migrated.version, PEER_CONFIG_VERSION);
+
+        // once grown to the current size, migrating again is a no-op that keeps the fields
+        let mut grown = vec![0u8; 8 + PeerConfig::INIT_SPACE];
+        let mut configured = migrated;
+        configured.credit_ceiling_sd = Some(1_000);
+        configured.try_serialize(&mut &mut grown[..]).unwrap();
+        let (again, changed) = PeerConfig::migrate(&grown).unwrap();
+        assert!(!changed);
+        assert_eq!(again.credit_ceiling_sd, Some(1_000));
+    }
+
+    #[test]
+    fn test_migrate_store_ignores_leftover_bytes_of_legacy_account() {
+        let legacy = |pauser: Option<Pubkey>| OFTStoreV0 {
+            oft_type: OFTType::Native,
+            ld2sd_rate: 1_000,
+            token_mint: Pubkey::new_unique(),
+            token_escrow: Pubkey::new_unique(),
+            endpoint_program: Pubkey::new_unique(),
+            bump: 254,
+            tvl_ld: 0,
+            admin: Pubkey::new_unique(),
+            default_fee_bps: 5,
+            paused: false,
+            pauser,
+            unpauser: pauser,
+        };
+        // a pauser and unpauser set then removed leave their keys in the padding
+        let mut data = vec![0u8; 8 + OFTStoreV0::INIT_SPACE];
+        data[..8].copy_from_slice(&OFTStore::DISCRIMINATOR);
+        legacy(Some(Pubkey::new_from_array([1; 32]))).serialize(&mut &mut data[8..]).unwrap();
+        legacy(None).serialize(&mut &mut data[8..]).unwrap();
+
+        let (migrated, changed) = OFTStore::migrate(&data).unwrap();
+        assert!(changed);
+        assert_eq!(migrated.ld2sd_rate, 1_000);
+        assert_eq!(migrated.default_fee_bps, 5);
+        assert_eq!(migrated.pauser, None);
+        assert_eq!(migrated.unpauser, None);
+        assert!(!migrated.admin_locked);
+        assert_eq!(migrated.max_supply_ld, None);
+        assert_eq!(migrated.bridged_supply_ld, 0);
+        assert_eq!(migrated.sponsor_reimbursement_rate, None);
+        assert_eq!(migrated.referral_share_bps, 0);
+        assert_eq!(migrated.referral_accrued_ld, 0);
+        assert_eq!(migrated.version, OFT_STORE_VERSION);
+
+        let mut grown = vec![0u8; 8 + OFTStore::INIT_SPACE];
+        migrated.try_serialize(&mut &mut grown[..]).unwrap();
+        let (_, changed) = OFTStore::migrate(&grown).unwrap();
+        assert!(!changed);
+    }
+
+    #[test]
+    fn test_migrate_peer_without_padding_reads_legacy_layout() {
+        // full enforced options and rate limiters leave no room for the appended fields
+        let enforced_options = EnforcedOptions {
+            send: vec![3; ENFORCED_OPTIONS_SEND_MAX_LEN],
+            send_and_call: vec![4; ENFORCED_OPTIONS_SEND_AND_CALL_MAX_LEN],
+        };
+        let data = legacy_peer(enforced_options, Some(RateLimiter::default()));
+        assert!(PeerConfig::try_deserialize(&mut &data[..]).is_err());
+
+        let (migrated, changed) = PeerConfig::migrate(&data).unwrap();
+        assert!(changed);
+        assert_eq!(migrated.peer_address, [1; 32]);
+        assert_eq!(
+            migrated.enforced_options.send,
+            vec![3; ENFORCED_OPTIONS_SEND_MAX_LEN]
+        );
+        assert_eq!(migrated.credit_ceiling_sd, None);
+        assert_eq!(migrated.total_sent_sd, 0);
+        assert_eq!(migrated.version, PEER_CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_rejects_other_accounts() {
+        let mut data = legacy_peer(
+            EnforcedOptions {
+                send: vec![3; 10],
+                send_and_call: vec![],
+            },
+            None,
+        );
+        data[..8].copy_from_slice(&[0; 8]);
+        assert!(PeerConfig::migrate(&data).is_err());
+    }
+}
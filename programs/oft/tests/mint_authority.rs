@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod test_mint_authority {
+    use anchor_lang::prelude::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        self,
+        instruction::MAX_SIGNERS,
+        solana_program::program_pack::Pack,
+        state::Multisig,
+    };
+    use oft::{
+        errors::OFTError,
+        instructions::{is_token_program_account, validate_mint_authority},
+    };
+
+    fn multisig(m: u8, signers: &[Pubkey]) -> Vec<u8> {
+        let mut padded = [Pubkey::default(); MAX_SIGNERS];
+        padded[..signers.len()].copy_from_slice(signers);
+        let multisig = Multisig { m, n: signers.len() as u8, is_initialized: true, signers: padded };
+        let mut data = vec![0u8; Multisig::LEN];
+        Multisig::pack(multisig, &mut data).unwrap();
+        data
+    }
+
+    fn validate(key: &Pubkey, owner: &Pubkey, mut data: Vec<u8>, oft_store: &Pubkey) -> Result<()> {
+        let mut lamports = 0;
+        let account = AccountInfo::new(key, false, false, &mut lamports, &mut data, owner, false, 0);
+        validate_mint_authority(&account, oft_store)
+    }
+
+    #[test]
+    fn test_store_is_mint_authority() {
+        let oft_store = Pubkey::new_unique();
+        // the store PDA is owned by the OFT program and holds no multisig
+        validate(&oft_store, &oft::ID, vec![], &oft_store).unwrap();
+    }
+
+    #[test]
+    fn test_one_of_n_multisig_with_store() {
+        let oft_store = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), oft_store, Pubkey::new_unique()];
+        for token_program in [anchor_spl::token::ID, spl_token_2022::ID] {
+            validate(&Pubkey::new_unique(), &token_program, multisig(1, &signers), &oft_store).unwrap();
+        }
+        validate(&Pubkey::new_unique(), &anchor_spl::token::ID, multisig(1, &[oft_store]), &oft_store)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_multisig_with_quorum_above_one() {
+        let oft_store = Pubkey::new_unique();
+        let signers = [oft_store, Pubkey::new_unique(), Pubkey::new_unique()];
+        for m in [2, 3] {
+            assert_eq!(
+                validate(&Pubkey::new_unique(), &anchor_spl::token::ID, multisig(m, &signers), &oft_store)
+                    .unwrap_err(),
+                OFTError::InvalidMintAuthority.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_multisig_without_store() {
+        let oft_store = Pubkey::new_unique();
+        let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+        assert_eq!(
+            validate(&Pubkey::new_unique(), &anchor_spl::token::ID, multisig(1, &signers), &oft_store)
+                .unwrap_err(),
+            OFTError::InvalidMintAuthority.into()
+        );
+
+        // only the first n signers count
+        let mut data = multisig(1, &signers);
+        let mut unpacked = Multisig::unpack(&data).unwrap();
+        unpacked.signers[2] = oft_store;
+        Multisig::pack(unpacked, &mut data).unwrap();
+        assert_eq!(
+            validate(&Pubkey::new_unique(), &anchor_spl::token::ID, data, &oft_store).unwrap_err(),
+            OFTError::InvalidMintAuthority.into()
+        );
+    }
+
+    #[test]
+    fn test_account_not_owned_by_token_program() {
+        let oft_store = Pubkey::new_unique();
+        let data = multisig(1, &[oft_store]);
+        for owner in [oft::ID, Pubkey::default(), Pubkey::new_unique()] {
+            let mut lamports = 0;
+            let mut data = data.clone();
+            let key = Pubkey::new_unique();
+            let account = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+            assert!(!is_token_program_account(&account));
+            assert_eq!(
+                validate_mint_authority(&account, &oft_store).unwrap_err(),
+                OFTError::InvalidMintAuthority.into()
+            );
+        }
+
+        // a token program account that is not a multisig
+        assert_eq!(
+            validate(&Pubkey::new_unique(), &anchor_spl::token::ID, vec![0u8; 82], &oft_store).unwrap_err(),
+            OFTError::InvalidMintAuthority.into()
+        );
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod test_oft_store {
+    use anchor_lang::{prelude::*, solana_program::native_token::LAMPORTS_PER_SOL};
+    use oft::{
+        errors::OFTError,
+        state::{OFTStore, OFTType, OFT_STORE_VERSION},
+    };
+
+    fn store(oft_type: OFTType, max_supply_ld: Option<u64>, bridged_supply_ld: u64) -> OFTStore {
+        OFTStore {
+            oft_type,
+            ld2sd_rate: 1000,
+            token_mint: Pubkey::new_unique(),
+            token_escrow: Pubkey::new_unique(),
+            endpoint_program: Pubkey::new_unique(),
+            bump: 255,
+            tvl_ld: 0,
+            admin: Pubkey::new_unique(),
+            default_fee_bps: 0,
+            paused: false,
+            pauser: None,
+            unpauser: None,
+            admin_locked: false,
+            max_supply_ld,
+            bridged_supply_ld,
+            sponsor_reimbursement_rate: None,
+            referral_share_bps: 0,
+            referral_accrued_ld: 0,
+            version: OFT_STORE_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_record_minted_up_to_max_supply() {
+        let mut store = store(OFTType::Native, Some(1_000), 400);
+        store.record_minted(600).unwrap();
+        assert_eq!(store.bridged_supply_ld, 1_000);
+        assert_eq!(store.supply_headroom_ld(), Some(0));
+
+        // minting past the cap is rejected and leaves the bridged supply untouched
+        assert_eq!(store.record_minted(1).unwrap_err(), OFTError::SupplyCapExceeded.into());
+        assert_eq!(store.bridged_supply_ld, 1_000);
+        store.record_minted(0).unwrap();
+    }
+
+    #[test]
+    fn test_record_minted_uncapped() {
+        let mut store = store(OFTType::Native, None, 0);
+        store.record_minted(u64::MAX).unwrap();
+        assert_eq!(store.bridged_supply_ld, u64::MAX);
+        assert_eq!(store.supply_headroom_ld(), None);
+
+        // an uncapped supply still can not overflow
+        assert_eq!(store.record_minted(1).unwrap_err(), OFTError::SupplyCapExceeded.into());
+        assert_eq!(store.bridged_supply_ld, u64::MAX);
+    }
+
+    #[test]
+    fn test_supply_headroom() {
+        assert_eq!(store(OFTType::Native, Some(1_000), 250).supply_headroom_ld(), Some(750));
+        assert_eq!(store(OFTType::Native, None, 250).supply_headroom_ld(), None);
+
+        // a cap lowered below the bridged supply leaves no headroom instead of underflowing
+        assert_eq!(store(OFTType::Native, Some(100), 250).supply_headroom_ld(), Some(0));
+
+        // an adapter does not mint, it has no headroom whatever max_supply_ld holds
+        assert_eq!(store(OFTType::Adapter, Some(1_000), 0).supply_headroom_ld(), None);
+    }
+
+    #[test]
+    fn test_set_max_supply() {
+        let mut native = store(OFTType::Native, None, 500);
+        native.set_max_supply(Some(100)).unwrap();
+        assert_eq!(native.max_supply_ld, Some(100));
+        native.set_max_supply(None).unwrap();
+        assert_eq!(native.max_supply_ld, None);
+
+        let mut adapter = store(OFTType::Adapter, None, 0);
+        assert_eq!(adapter.set_max_supply(Some(100)).unwrap_err(), OFTError::NativeOnly.into());
+        assert_eq!(adapter.set_max_supply(None).unwrap_err(), OFTError::NativeOnly.into());
+        assert_eq!(adapter.max_supply_ld, None);
+    }
+
+    #[test]
+    fn test_sponsor_reimbursement_rounds_up() {
+        let mut store = store(OFTType::Native, None, 0);
+        assert_eq!(store.sponsor_reimbursement_ld(5_000).unwrap(), 0);
+
+        // 2_000_000 tokens per SOL is 0.002 tokens per lamport
+        store.sponsor_reimbursement_rate = Some(2_000_000);
+        assert_eq!(store.sponsor_reimbursement_ld(0).unwrap(), 0);
+        assert_eq!(store.sponsor_reimbursement_ld(1).unwrap(), 1);
+        assert_eq!(store.sponsor_reimbursement_ld(500).unwrap(), 1);
+        assert_eq!(store.sponsor_reimbursement_ld(501).unwrap(), 2);
+        assert_eq!(store.sponsor_reimbursement_ld(LAMPORTS_PER_SOL).unwrap(), 2_000_000);
+        assert_eq!(store.sponsor_reimbursement_ld(LAMPORTS_PER_SOL + 1).unwrap(), 2_000_001);
+
+        // the product is computed without overflow, only a result beyond u64 is rejected
+        store.sponsor_reimbursement_rate = Some(u64::MAX);
+        assert_eq!(store.sponsor_reimbursement_ld(LAMPORTS_PER_SOL).unwrap(), u64::MAX);
+        assert_eq!(
+            store.sponsor_reimbursement_ld(LAMPORTS_PER_SOL + 1).unwrap_err(),
+            OFTError::SponsorReimbursementExceeded.into()
+        );
+    }
+}
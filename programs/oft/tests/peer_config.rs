@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod test_peer_config {
+    use oft::{
+        errors::OFTError,
+        instructions::RateLimitCapacity,
+        state::{EnforcedOptions, PeerConfig, RateLimiter, RateLimiterType, PEER_CONFIG_VERSION},
+    };
+
+    fn config(total_sent_sd: u64, credit_ceiling_sd: Option<u64>) -> PeerConfig {
+        PeerConfig {
+            peer_address: [1; 32],
+            enforced_options: EnforcedOptions::default(),
+            outbound_rate_limiter: None,
+            inbound_rate_limiter: None,
+            fee_bps: None,
+            bump: 255,
+            total_sent_sd,
+            total_received_sd: 0,
+            credit_ceiling_sd,
+            ata_creation_fee_ld: None,
+            version: PEER_CONFIG_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_record_received_up_to_credit_ceiling() {
+        let mut peer = config(100, Some(50));
+        peer.record_received(120).unwrap();
+        peer.record_received(30).unwrap();
+        assert_eq!(peer.total_received_sd, 150);
+
+        // one more unit exceeds total_sent_sd + credit_ceiling_sd and leaves the ledger untouched
+        assert_eq!(peer.record_received(1).unwrap_err(), OFTError::CreditCeilingExceeded.into());
+        assert_eq!(peer.total_received_sd, 150);
+
+        // sending to the peer raises the ceiling again
+        peer.record_sent(1);
+        peer.record_received(1).unwrap();
+        assert_eq!(peer.total_received_sd, 151);
+    }
+
+    #[test]
+    fn test_record_received_with_zero_ceiling() {
+        let mut peer = config(0, Some(0));
+        assert_eq!(peer.record_received(1).unwrap_err(), OFTError::CreditCeilingExceeded.into());
+        peer.record_received(0).unwrap();
+        assert_eq!(peer.total_received_sd, 0);
+    }
+
+    #[test]
+    fn test_record_received_without_ceiling() {
+        let mut peer = config(0, None);
+        peer.record_received(u64::MAX / 2).unwrap();
+        peer.record_received(u64::MAX / 2).unwrap();
+        assert_eq!(peer.total_received_sd, u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_record_received_saturates() {
+        // the received total saturates instead of overflowing
+        let mut peer = config(0, None);
+        peer.record_received(u64::MAX).unwrap();
+        peer.record_received(1).unwrap();
+        assert_eq!(peer.total_received_sd, u64::MAX);
+
+        // the ceiling saturates too, total_sent_sd + credit_ceiling_sd never wraps to a lower bound
+        let mut capped = config(u64::MAX - 1, Some(10));
+        capped.record_received(u64::MAX).unwrap();
+        assert_eq!(capped.total_received_sd, u64::MAX);
+
+        // the sent total saturates
+        capped.record_sent(u64::MAX);
+        assert_eq!(capped.total_sent_sd, u64::MAX);
+    }
+
+    fn rate_limiter(available_capacity: u64, refill_per_second: u64) -> Option<RateLimiter> {
+        Some(RateLimiter {
+            capacity: 1_000,
+            available_capacity,
+            refill_per_second,
+            last_refill_time: 1_000,
+            rate_limiter_type: RateLimiterType::Net,
+        })
+    }
+
+    #[test]
+    fn test_rate_limit_capacity_without_limiter() {
+        // the direction is blocked without a rate limiter, whatever the clock
+        for current_time in [0, 1_000, u64::MAX] {
+            let capacity = RateLimitCapacity::of(&None, current_time);
+            assert_eq!(capacity.available_capacity, 0);
+            assert_eq!(capacity.capacity, 0);
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_capacity_refills_over_time() {
+        let limiter = rate_limiter(100, 10);
+        let at = |current_time| RateLimitCapacity::of(&limiter, current_time).available_capacity;
+        assert_eq!(at(1_000), 100);
+        assert_eq!(at(1_001), 110);
+        assert_eq!(at(1_050), 600);
+        assert_eq!(at(1_090), 1_000);
+        assert_eq!(at(5_000), 1_000);
+        assert_eq!(RateLimitCapacity::of(&limiter, 5_000).capacity, 1_000);
+
+        // a clock behind the last refill does not refill
+        assert_eq!(at(999), 100);
+
+        // the view does not persist the refill
+        let limiter = limiter.unwrap();
+        assert_eq!(limiter.available_capacity, 100);
+        assert_eq!(limiter.last_refill_time, 1_000);
+    }
+
+    #[test]
+    fn test_rate_limit_capacity_saturates() {
+        let capacity = RateLimitCapacity::of(&rate_limiter(100, u64::MAX), u64::MAX);
+        assert_eq!(capacity.available_capacity, 1_000);
+
+        let capacity = RateLimitCapacity::of(&rate_limiter(0, 0), u64::MAX);
+        assert_eq!(capacity.available_capacity, 0);
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod test_send_sponsored {
+    use anchor_lang::prelude::*;
+    use oft::{errors::OFTError, instructions::validate_sponsor};
+
+    struct TestAccount {
+        key: Pubkey,
+        is_signer: bool,
+        lamports: u64,
+        data: Vec<u8>,
+        owner: Pubkey,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, is_signer: bool) -> Self {
+            Self { key, is_signer, lamports: 0, data: vec![], owner: Pubkey::default() }
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                self.is_signer,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    fn validate(sponsor: &Pubkey, accounts: &mut [TestAccount]) -> Result<()> {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(|account| account.info()).collect();
+        validate_sponsor(sponsor, &infos)
+    }
+
+    #[test]
+    fn test_sponsor_pays_the_endpoint() {
+        let sponsor = Pubkey::new_unique();
+        let mut accounts = [
+            TestAccount::new(Pubkey::new_unique(), false),
+            TestAccount::new(sponsor, true),
+            TestAccount::new(Pubkey::new_unique(), false),
+        ];
+        validate(&sponsor, &mut accounts).unwrap();
+
+        // the sponsor may appear more than once
+        let mut accounts = [TestAccount::new(sponsor, true), TestAccount::new(sponsor, true)];
+        validate(&sponsor, &mut accounts).unwrap();
+    }
+
+    #[test]
+    fn test_other_signer_pays_the_endpoint() {
+        let sponsor = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+
+        // the endpoint payer is another signer, e.g. the sender
+        let mut accounts = [TestAccount::new(signer, true), TestAccount::new(sponsor, false)];
+        assert_eq!(validate(&sponsor, &mut accounts).unwrap_err(), OFTError::InvalidSponsor.into());
+
+        // a second signer besides the sponsor
+        let mut accounts = [TestAccount::new(sponsor, true), TestAccount::new(signer, true)];
+        assert_eq!(validate(&sponsor, &mut accounts).unwrap_err(), OFTError::InvalidSponsor.into());
+
+        // no signer at all
+        let mut accounts = [TestAccount::new(sponsor, false)];
+        assert_eq!(validate(&sponsor, &mut accounts).unwrap_err(), OFTError::InvalidSponsor.into());
+        assert_eq!(validate(&sponsor, &mut []).unwrap_err(), OFTError::InvalidSponsor.into());
+    }
+}
@@ -62,16 +62,16 @@ export async function setPeers(
 ): Promise<void> {
     const ix = programs.governanceProgram.setRemote(admin.publicKey, remotePeer, remote)
     const [remotePDA] = programs.governanceProgram.governanceDeriver.remote(remote)
-    let current = ''
+    let current: string[] = []
     try {
         const info = await GovernanceProgram.accounts.Remote.fromAccountAddress(connection, remotePDA, {
             commitment,
         })
-        current = Buffer.from(info.address).toString('hex')
+        current = info.senders.filter((sender) => sender.enabled).map((sender) => Buffer.from(sender.address).toString('hex'))
     } catch (e) {
         /*remote not init*/
     }
-    if (current == Buffer.from(remotePeer).toString('hex')) {
+    if (current.includes(Buffer.from(remotePeer).toString('hex'))) {
         console.log('setRemote: already set');
         return Promise.resolve()
     }
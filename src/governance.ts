@@ -15,6 +15,9 @@ import { GovernancePDADeriver } from './governance-pda-deriver'
 
 export { accounts, instructions, types }
 
+// LayerZero executor, the execution context of CONTEXT_PLACEHOLDER is derived from it
+const EXECUTOR_PROGRAM_ID = new PublicKey('6doghB248px58JSSwG4qejQ46kFMW4AMj7vzJnWZHNZn')
+
 export class Governance {
     governanceDeriver: GovernancePDADeriver
 
@@ -35,7 +38,10 @@ export class Governance {
         payer: PublicKey,
         admin: PublicKey,
         lzReceiveAlts: PublicKey[] = [],
-        commitmentOrConfig: Commitment | GetAccountInfoConfig = 'confirmed'
+        commitmentOrConfig: Commitment | GetAccountInfoConfig = 'confirmed',
+        minDelay: number = 0,
+        guardian: PublicKey | null = null,
+        executorId: PublicKey = EXECUTOR_PROGRAM_ID
     ): Promise<TransactionInstruction | null> {
         const [id] = this.idPDA()
         const [oAppRegistry] = this.endpoint.deriver.oappRegistry(id)
@@ -89,22 +95,46 @@ export class Governance {
                     id: this.governanceId,
                     admin,
                     lzReceiveAlts,
+                    minDelay,
+                    guardian,
+                    executorId,
                 } satisfies types.InitGovernanceParams,
             } satisfies instructions.InitGovernanceInstructionArgs,
             this.program
         )
     }
 
+    migrateGovernance(payer: PublicKey): TransactionInstruction {
+        return instructions.createMigrateGovernanceInstruction(
+            {
+                payer,
+                governance: this.idPDA()[0],
+            } satisfies instructions.MigrateGovernanceInstructionAccounts,
+            this.program
+        )
+    }
+
+    migrateRemote(payer: PublicKey, remoteEid: number): TransactionInstruction {
+        const [remotePDA] = this.governanceDeriver.remote(remoteEid)
+        return instructions.createMigrateRemoteInstruction(
+            {
+                payer,
+                remote: remotePDA,
+            } satisfies instructions.MigrateRemoteInstructionAccounts,
+            this.program
+        )
+    }
+
     async getRemote(
         connection: Connection,
         dstEid: number,
         commitmentOrConfig?: Commitment | GetAccountInfoConfig
-    ): Promise<Uint8Array | null> {
+    ): Promise<Uint8Array[] | null> {
         const [remotePDA] = this.governanceDeriver.remote(dstEid)
         const info = await connection.getAccountInfo(remotePDA, commitmentOrConfig)
         if (info) {
             const remote = await accounts.Remote.fromAccountAddress(connection, remotePDA, commitmentOrConfig)
-            return Uint8Array.from(remote.address)
+            return remote.senders.filter((sender) => sender.enabled).map((sender) => Uint8Array.from(sender.address))
         }
         return null
     }
@@ -122,7 +152,12 @@ export class Governance {
         return null
     }
 
-    setRemote(admin: PublicKey, dstAddress: Uint8Array, remoteEid: number): TransactionInstruction {
+    setRemote(
+        admin: PublicKey,
+        dstAddress: Uint8Array,
+        remoteEid: number,
+        strictOrdering: boolean = false
+    ): TransactionInstruction {
         const [remotePDA] = this.governanceDeriver.remote(remoteEid)
         return instructions.createSetRemoteInstruction(
             {
@@ -134,12 +169,73 @@ export class Governance {
                 params: {
                     remoteEid,
                     remote: Array.from(dstAddress),
+                    strictOrdering,
                 } satisfies types.SetRemoteParams,
             },
             this.program
         )
     }
 
+    removeRemote(admin: PublicKey, remoteEid: number, rentRecipient: PublicKey = admin): TransactionInstruction {
+        const [remotePDA] = this.governanceDeriver.remote(remoteEid)
+        return instructions.createRemoveRemoteInstruction(
+            {
+                admin,
+                remote: remotePDA,
+                rentRecipient,
+                governance: this.idPDA()[0],
+            } satisfies instructions.RemoveRemoteInstructionAccounts,
+            {
+                params: {
+                    remoteEid,
+                } satisfies types.RemoveRemoteParams,
+            },
+            this.program
+        )
+    }
+
+    addRemoteSender(
+        admin: PublicKey,
+        sender: Uint8Array,
+        remoteEid: number,
+        enabled: boolean = true
+    ): TransactionInstruction {
+        const [remotePDA] = this.governanceDeriver.remote(remoteEid)
+        return instructions.createAddRemoteSenderInstruction(
+            {
+                admin,
+                remote: remotePDA,
+                governance: this.idPDA()[0],
+            } satisfies instructions.AddRemoteSenderInstructionAccounts,
+            {
+                params: {
+                    remoteEid,
+                    sender: Array.from(sender),
+                    enabled,
+                } satisfies types.AddRemoteSenderParams,
+            },
+            this.program
+        )
+    }
+
+    removeRemoteSender(admin: PublicKey, sender: Uint8Array, remoteEid: number): TransactionInstruction {
+        const [remotePDA] = this.governanceDeriver.remote(remoteEid)
+        return instructions.createRemoveRemoteSenderInstruction(
+            {
+                admin,
+                remote: remotePDA,
+                governance: this.idPDA()[0],
+            } satisfies instructions.RemoveRemoteSenderInstructionAccounts,
+            {
+                params: {
+                    remoteEid,
+                    sender: Array.from(sender),
+                } satisfies types.RemoveRemoteSenderParams,
+            },
+            this.program
+        )
+    }
+
     setLzReceiveTypesAccounts(admin: PublicKey, lzReceiveAlts: PublicKey[]): TransactionInstruction {
         const [lzReceiveTypesInfoAccountsPDA] = this.governanceDeriver.lzReceiveTypesInfoAccounts()
         return instructions.createSetOappConfigInstruction(
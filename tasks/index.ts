@@ -21,4 +21,5 @@ import './solana/setALTs'
 import './solana/alt'
 import './solana/pause'
 import './solana/setPauser'
-import './solana/setUnpauser'
\ No newline at end of file
+import './solana/setUnpauser'
+import './solana/setMaxSupply'
@@ -14,7 +14,7 @@ import {
     transactionBuilder,
 } from '@metaplex-foundation/umi'
 import { fromWeb3JsPublicKey, toWeb3JsPublicKey } from '@metaplex-foundation/umi-web3js-adapters'
-import { TOKEN_PROGRAM_ID } from '@solana/spl-token'
+import { TOKEN_PROGRAM_ID, getMint } from '@solana/spl-token'
 import { PublicKey } from '@solana/web3.js'
 import bs58 from 'bs58'
 import { task } from 'hardhat/config'
@@ -22,9 +22,11 @@ import { task } from 'hardhat/config'
 import { types as devtoolsTypes } from '@layerzerolabs/devtools-evm-hardhat'
 import { promptToContinue } from '@layerzerolabs/io-devtools'
 import { EndpointId } from '@layerzerolabs/lz-definitions'
-import { OFT_DECIMALS as DEFAULT_SHARED_DECIMALS, oft } from '@layerzerolabs/oft-v2-solana-sdk'
+import { OFT_DECIMALS as DEFAULT_SHARED_DECIMALS } from '@layerzerolabs/oft-v2-solana-sdk'
 
 import { checkMultisigSigners, createMintAuthorityMultisig } from './multisig'
+import { OFTType } from './sdk/generated/oft302'
+import { initOft } from './sdk/oft302'
 import { assertAccountInitialized } from './utils'
 
 import {
@@ -264,15 +266,22 @@ task('lz:oft:solana:create', 'Mints new SPL Token and creates new OFT Store acco
             }
 
             const lockboxSigner = createSignerFromKeypair({ eddsa: eddsa }, lockBox)
+            const { mintAuthority: currentMintAuthority } = await getMint(
+                connection,
+                toWeb3JsPublicKey(mint.publicKey),
+                undefined,
+                toWeb3JsPublicKey(tokenProgramId)
+            )
             let txBuilder = transactionBuilder().add(
-                oft.initOft(
+                initOft(
                     {
                         payer: umiWalletSigner,
                         admin: umiWalletKeyPair.publicKey,
                         mint: mint.publicKey,
                         escrow: lockboxSigner,
+                        mintAuthority: currentMintAuthority ? fromWeb3JsPublicKey(currentMintAuthority) : undefined,
                     },
-                    oft.types.OFTType.Native,
+                    OFTType.Native,
                     sharedDecimals,
                     {
                         oft: programId,
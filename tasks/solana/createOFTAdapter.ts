@@ -6,8 +6,10 @@ import { task } from 'hardhat/config'
 
 import { types as devtoolsTypes } from '@layerzerolabs/devtools-evm-hardhat'
 import { EndpointId } from '@layerzerolabs/lz-definitions'
-import { OFT_DECIMALS, oft } from '@layerzerolabs/oft-v2-solana-sdk'
+import { OFT_DECIMALS } from '@layerzerolabs/oft-v2-solana-sdk'
 
+import { OFTType } from './sdk/generated/oft302'
+import { initOft } from './sdk/oft302'
 import {
     TransactionType,
     addComputeUnitInstructions,
@@ -67,14 +69,14 @@ task('lz:oft-adapter:solana:create', 'Creates new OFT Adapter (OFT Store PDA)')
             const mintAuthority = mintPDA.mintAuthority
 
             let txBuilder = transactionBuilder().add(
-                oft.initOft(
+                initOft(
                     {
                         payer: createSignerFromKeypair({ eddsa: eddsa }, umiWalletKeyPair),
                         admin: umiWalletKeyPair.publicKey,
                         mint: mint,
                         escrow: createSignerFromKeypair({ eddsa: eddsa }, lockBox),
                     },
-                    oft.types.OFTType.Adapter,
+                    OFTType.Adapter,
                     OFT_DECIMALS,
                     {
                         oft: programId,
@@ -133,11 +133,14 @@ task('lz:oft:solana:debug', 'Manages OFTStore and OAppRegistry information')
             DebugLogger.keyValue('OFT Program', oftStoreInfo.header.owner)
             DebugLogger.keyValue('OFT Type', oft302types.OFTType[oftStoreInfo.oftType])
             DebugLogger.keyValue('Admin', oftStoreInfo.admin)
+            DebugLogger.keyValue('Admin Locked', oftStoreInfo.adminLocked)
             DebugLogger.keyValue('Token Mint', oftStoreInfo.tokenMint)
             DebugLogger.keyValue('Token Escrow', oftStoreInfo.tokenEscrow)
             DebugLogger.keyValue('Endpoint Program', oftStoreInfo.endpointProgram)
             DebugLogger.keyValue('Pauser', JSON.stringify(oftStoreInfo.pauser))
             DebugLogger.keyValue('Unpauser', JSON.stringify(oftStoreInfo.unpauser))
+            DebugLogger.keyValue('Max Supply', JSON.stringify(oftStoreInfo.maxSupplyLd, (_, v) => (typeof v === 'bigint' ? v.toString() : v)))
+            DebugLogger.keyValue('Bridged Supply', oftStoreInfo.bridgedSupplyLd.toString())
             DebugLogger.separator()
         }
 
@@ -1,13 +1,13 @@
-import { mplToolbox } from '@metaplex-foundation/mpl-toolbox'
-import { publicKey } from '@metaplex-foundation/umi'
-import { createUmi } from '@metaplex-foundation/umi-bundle-defaults'
+import { publicKey, transactionBuilder } from '@metaplex-foundation/umi'
 import { task } from 'hardhat/config'
+
 import { types } from '@layerzerolabs/devtools-evm-hardhat'
 import { EndpointId } from '@layerzerolabs/lz-definitions'
 
-import { createSolanaConnectionFactory } from '../common/utils'
-import { accounts } from './sdk/oft302'
+import { deriveConnection } from './index'
+import { accounts, getRateLimitCapacity, types as oftTypes } from './sdk/oft302'
 import { OftPDA } from './sdk/pda'
+import { simulateTransaction } from './utils'
 
 interface Args {
     mint: string
@@ -24,12 +24,35 @@ task('lz:oft:solana:get-rate-limits', 'Gets the Solana inbound / outbound rate l
     .addParam('dstEid', 'The destination endpoint ID', undefined, types.eid)
     .addParam('oftStore', 'The OFTStore account')
     .setAction(async (taskArgs: Args, _) => {
-        const connectionFactory = createSolanaConnectionFactory()
-        const connection = await connectionFactory(taskArgs.eid)
-        const umi = createUmi(connection.rpcEndpoint).use(mplToolbox())
+        const { umi, connection } = await deriveConnection(taskArgs.eid)
+        const programId = publicKey(taskArgs.programId)
+        const oftStore = publicKey(taskArgs.oftStore)
 
-        const [peer] = new OftPDA(publicKey(taskArgs.programId)).peer(publicKey(taskArgs.oftStore), taskArgs.dstEid)
+        const [peer] = new OftPDA(programId).peer(oftStore, taskArgs.dstEid)
         const peerInfo = await accounts.fetchPeerConfig({ rpc: umi.rpc }, peer)
+
+        // the capacity views refill the rate limiters to the current clock, the stored
+        // available_capacity is only up to date as of the last send or receive
+        const capacities: Record<string, { availableCapacity: string; capacity: string }> = {}
+        for (const direction of ['outbound', 'inbound'] as const) {
+            const transaction = await transactionBuilder()
+                .add(getRateLimitCapacity({ oftStore }, taskArgs.dstEid, direction, programId))
+                .buildWithLatestBlockhash(umi)
+            const simulation = await simulateTransaction(umi, transaction, connection)
+            if (simulation.err || !simulation.returnData) {
+                throw new Error(`Failed to simulate the ${direction} rate limit view: ${JSON.stringify(simulation.err)}`)
+            }
+            const [capacity] = oftTypes
+                .getRateLimitCapacitySerializer()
+                .deserialize(Buffer.from(simulation.returnData.data[0], 'base64'))
+            capacities[direction] = {
+                availableCapacity: capacity.availableCapacity.toString(),
+                capacity: capacity.capacity.toString(),
+            }
+        }
+
         console.log(`Peer info between ${taskArgs.eid} and ${taskArgs.dstEid}`)
         console.dir({ peerInfo }, { depth: null })
+        console.log(`Rate limit capacities between ${taskArgs.eid} and ${taskArgs.dstEid}, in local decimals`)
+        console.dir(capacities, { depth: null })
     })
@@ -13,12 +13,17 @@ import {
 } from '@metaplex-foundation/umi'
 import { createDefaultProgramRepository } from '@metaplex-foundation/umi-program-repository'
 
+import { EndpointProgram } from '@layerzerolabs/lz-solana-sdk-v2/umi'
+
 import * as errors from './generated/oft302/errors'
 import * as instructions from './generated/oft302/instructions'
 import * as types from './generated/oft302/types'
+import { OFT_DECIMALS } from './consts'
 import { OftPDA } from './pda'
 import {
     SetPeerAddressParam,
+    SetPeerAtaCreationFeeParam,
+    SetPeerCreditCeilingParam,
     SetPeerEnforcedOptionsParam,
     SetPeerFeeBpsParam,
     SetPeerRateLimitParam,
@@ -53,12 +58,64 @@ export function createOFTProgramRepo(oftProgram: PublicKey, rpc?: RpcInterface):
     ])
 }
 
+export function initOft(
+    accounts: {
+        payer: Signer
+        admin: PublicKey
+        mint: PublicKey
+        escrow: Signer
+        // current mint authority, required for Native unless it is the OFT store
+        mintAuthority?: PublicKey
+    },
+    type: types.OFTType,
+    sharedDecimals: number = OFT_DECIMALS,
+    programs: {
+        oft: PublicKey | ProgramRepositoryInterface
+        endpoint?: PublicKey
+        token?: PublicKey
+    }
+): WrappedInstruction {
+    const programsRepo = typeof programs.oft === 'string' ? createOFTProgramRepo(programs.oft) : programs.oft
+    const { payer, admin, mint, escrow, mintAuthority } = accounts
+    const deriver = new OftPDA(programsRepo.getPublicKey('oft'))
+    const [oftStore] = deriver.oftStore(escrow.publicKey)
+    const [lzReceiveTypesAccounts] = deriver.lzReceiveTypesAccounts(oftStore)
+    const endpoint = new EndpointProgram.Endpoint(programs.endpoint ?? EndpointProgram.ENDPOINT_PROGRAM_ID)
+    return instructions
+        .initOft(
+            { payer, programs: programsRepo },
+            {
+                payer,
+                oftStore,
+                lzReceiveTypesAccounts,
+                tokenMint: mint,
+                tokenEscrow: escrow,
+                tokenProgram: programs.token,
+                mintAuthority,
+                // params
+                oftType: type,
+                admin,
+                sharedDecimals,
+                endpointProgram: programs.endpoint ?? null,
+            }
+        )
+        .addRemainingAccounts(endpoint.getRegisterOappIxAccountMetaForCPI(payer.publicKey, oftStore))
+        .items[0]
+}
+
 export function setPeerConfig(
     accounts: {
         admin: Signer
         oftStore: PublicKey
     },
-    param: (SetPeerAddressParam | SetPeerFeeBpsParam | SetPeerEnforcedOptionsParam | SetPeerRateLimitParam) & {
+    param: (
+        | SetPeerAddressParam
+        | SetPeerFeeBpsParam
+        | SetPeerEnforcedOptionsParam
+        | SetPeerRateLimitParam
+        | SetPeerCreditCeilingParam
+        | SetPeerAtaCreationFeeParam
+    ) & {
         remote: number
     },
     oftProgramId: PublicKey | ProgramRepositoryInterface
@@ -84,6 +141,16 @@ export function setPeerConfig(
             send: param.send,
             sendAndCall: param.sendAndCall,
         }
+    } else if (param.__kind === 'CreditCeiling') {
+        config = {
+            __kind: 'CreditCeiling',
+            fields: [param.creditCeilingSd !== undefined ? some(param.creditCeilingSd) : null],
+        }
+    } else if (param.__kind === 'AtaCreationFee') {
+        config = {
+            __kind: 'AtaCreationFee',
+            fields: [param.ataCreationFeeLd !== undefined ? some(param.ataCreationFeeLd) : null],
+        }
         // eslint-disable-next-line @typescript-eslint/no-unnecessary-condition
     } else if (param.__kind === 'OutboundRateLimit' || param.__kind === 'InboundRateLimit') {
         config = {
@@ -131,6 +198,40 @@ export function setOFTConfig(
     ).items[0]
 }
 
+export function closePeer(
+    accounts: {
+        admin: Signer
+        oftStore: PublicKey
+        rentRecipient: PublicKey
+    },
+    remoteEid: number,
+    oftProgramId: PublicKey | ProgramRepositoryInterface
+): WrappedInstruction {
+    const programsRepo = typeof oftProgramId === 'string' ? createOFTProgramRepo(oftProgramId) : oftProgramId
+    const { admin, oftStore, rentRecipient } = accounts
+    const [peerPda] = new OftPDA(programsRepo.getPublicKey('oft')).peer(oftStore, remoteEid)
+    return instructions.closePeer(
+        { programs: programsRepo },
+        { admin, peer: peerPda, rentRecipient, oftStore, remoteEid }
+    ).items[0]
+}
+
+export function renounceLocalAdmin(
+    accounts: {
+        admin: Signer
+        oftStore: PublicKey
+    },
+    params: types.RenounceLocalAdminParams,
+    oftProgramId: PublicKey | ProgramRepositoryInterface
+): WrappedInstruction {
+    const programsRepo = typeof oftProgramId === 'string' ? createOFTProgramRepo(oftProgramId) : oftProgramId
+    const { admin, oftStore } = accounts
+    return instructions.renounceLocalAdmin(
+        { programs: programsRepo },
+        { admin, oftStore, params }
+    ).items[0]
+}
+
 export function setPause(
     accounts: {
         signer: Signer
@@ -145,4 +246,63 @@ export function setPause(
         { programs: programsRepo },
         { signer, oftStore: oftStore, paused }
     ).items[0]
-}
\ No newline at end of file
+}
+export function transferMintAuthority(
+    accounts: {
+        admin: Signer
+        oftStore: PublicKey
+        tokenMint: PublicKey
+        mintAuthority: PublicKey
+        tokenProgram?: PublicKey
+    },
+    newAuthority: PublicKey | null,
+    oftProgramId: PublicKey | ProgramRepositoryInterface
+): WrappedInstruction {
+    const programsRepo = typeof oftProgramId === 'string' ? createOFTProgramRepo(oftProgramId) : oftProgramId
+    const { admin, oftStore, tokenMint, mintAuthority, tokenProgram } = accounts
+    return instructions.transferMintAuthority(
+        { programs: programsRepo },
+        { admin, oftStore, tokenMint, mintAuthority, tokenProgram, newAuthority }
+    ).items[0]
+}
+
+export function migrateStore(
+    accounts: {
+        payer: Signer
+        oftStore: PublicKey
+    },
+    oftProgramId: PublicKey | ProgramRepositoryInterface
+): WrappedInstruction {
+    const programsRepo = typeof oftProgramId === 'string' ? createOFTProgramRepo(oftProgramId) : oftProgramId
+    const { payer, oftStore } = accounts
+    return instructions.migrateStore({ programs: programsRepo }, { payer, oftStore }).items[0]
+}
+
+export function migratePeer(
+    accounts: {
+        payer: Signer
+        oftStore: PublicKey
+    },
+    remoteEid: number,
+    oftProgramId: PublicKey | ProgramRepositoryInterface
+): WrappedInstruction {
+    const programsRepo = typeof oftProgramId === 'string' ? createOFTProgramRepo(oftProgramId) : oftProgramId
+    const { payer, oftStore } = accounts
+    const [peerPda] = new OftPDA(programsRepo.getPublicKey('oft')).peer(oftStore, remoteEid)
+    return instructions.migratePeer({ programs: programsRepo }, { payer, peer: peerPda }).items[0]
+}
+
+export function getRateLimitCapacity(
+    accounts: {
+        oftStore: PublicKey
+    },
+    remoteEid: number,
+    direction: 'outbound' | 'inbound',
+    oftProgramId: PublicKey | ProgramRepositoryInterface
+): WrappedInstruction {
+    const programsRepo = typeof oftProgramId === 'string' ? createOFTProgramRepo(oftProgramId) : oftProgramId
+    const { oftStore } = accounts
+    const [peerPda] = new OftPDA(programsRepo.getPublicKey('oft')).peer(oftStore, remoteEid)
+    const view = direction === 'outbound' ? instructions.getAmountCanBeSent : instructions.getAmountCanBeReceived
+    return view({ programs: programsRepo }, { oftStore, peer: peerPda, params: { remoteEid } }).items[0]
+}
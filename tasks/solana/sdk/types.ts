@@ -27,6 +27,16 @@ export interface SetPeerRateLimitParam {
     __kind: 'OutboundRateLimit' | 'InboundRateLimit'
 }
 
+export interface SetPeerCreditCeilingParam {
+    creditCeilingSd?: bigint
+    __kind: 'CreditCeiling'
+}
+
+export interface SetPeerAtaCreationFeeParam {
+    ataCreationFeeLd?: bigint
+    __kind: 'AtaCreationFee'
+}
+
 export interface SetPeerIsEndpointV1Param {
     isEndpointV1: boolean
     __kind: 'IsEndpointV1'
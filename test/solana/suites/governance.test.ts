@@ -92,6 +92,9 @@ describe('🏗️ Governance', function () {
                 id: new anchor.BN(0),
                 admin: notDeployer.publicKey,
                 lzReceiveAlts: [],
+                minDelay: new anchor.BN(0),
+                guardian: null,
+                executorId: new PublicKey('6doghB248px58JSSwG4qejQ46kFMW4AMj7vzJnWZHNZn'),
             })
             .accountsStrict({
                 payer: notDeployer.publicKey,
@@ -149,6 +152,9 @@ describe('🏗️ Governance', function () {
                 id: new anchor.BN(0),
                 admin: deployer.publicKey,
                 lzReceiveAlts: [],
+                minDelay: new anchor.BN(0),
+                guardian: null,
+                executorId: new PublicKey('6doghB248px58JSSwG4qejQ46kFMW4AMj7vzJnWZHNZn'),
             })
             .accountsStrict({
                 payer: deployer.publicKey,
@@ -188,6 +194,7 @@ describe('🏗️ Governance', function () {
             await governance.methods.setRemote({
                 remoteEid: dummyEid,
                 remote: Array.from(dummyRemoteBytes),
+                strictOrdering: false,
             })
             .accountsStrict({
                 admin: deployer.publicKey,
@@ -202,7 +209,9 @@ describe('🏗️ Governance', function () {
     
         const remoteAccount = await governance.account.remote.fetch(remotePDA[0]);
         assert.notEqual(remoteAccount, null);
-        assert.strictEqual(Buffer.from(remoteAccount.address).toString('hex'), dummyRemote);
+        assert.strictEqual(remoteAccount.senders.length, 1);
+        assert.strictEqual(Buffer.from(remoteAccount.senders[0].address).toString('hex'), dummyRemote);
+        assert.strictEqual(remoteAccount.senders[0].enabled, true);
     });
 
     it('configures delegate', async () => {